@@ -0,0 +1,62 @@
+use std::hint::black_box;
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Benchmarks parsing and both parts of a day on its real input, skipped when the input isn't there
+fn bench_day<S: Solution>(c: &mut Criterion, day: usize) {
+    let Some(input) = common::input::workspace_input(day) else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("day{:02}", day));
+    group.bench_function("parse", |b| {
+        b.iter(|| S::parse(black_box(input.as_slice())).unwrap())
+    });
+
+    let parsed = S::parse(input.as_slice()).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| S::part1(black_box(&parsed)).unwrap())
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| S::part2(black_box(&parsed)).unwrap())
+    });
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, 1);
+    bench_day::<day02::Day02>(c, 2);
+    bench_day::<day03::Day03>(c, 3);
+    bench_day::<day04::Day04>(c, 4);
+    bench_day::<day05::Day05>(c, 5);
+    bench_day::<day06::Day06>(c, 6);
+    bench_day::<day07::Day07>(c, 7);
+    bench_day::<day08::Day08>(c, 8);
+    bench_day::<day09::Day09>(c, 9);
+    bench_day::<day10::Day10>(c, 10);
+    bench_day::<day11::Day11>(c, 11);
+    bench_day::<day12::Day12>(c, 12);
+    bench_day::<day13::Day13>(c, 13);
+    bench_day::<day14::Day14>(c, 14);
+    bench_day::<day15::Day15>(c, 15);
+    bench_day::<day16::Day16>(c, 16);
+    bench_day::<day17::Day17>(c, 17);
+    bench_day::<day18::Day18>(c, 18);
+    bench_day::<day19::Day19>(c, 19);
+    bench_day::<day20::Day20>(c, 20);
+    bench_day::<day21::Day21>(c, 21);
+    bench_day::<day22::Day22>(c, 22);
+    bench_day::<day23::Day23>(c, 23);
+    bench_day::<day24::Day24>(c, 24);
+    bench_day::<day25::Day25>(c, 25);
+}
+
+criterion_group! {
+    name = benches;
+    // Some days take seconds per iteration, fewer samples keep the whole suite under a few minutes
+    config = Criterion::default().sample_size(20);
+    targets = days
+}
+criterion_main!(benches);
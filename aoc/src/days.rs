@@ -0,0 +1,44 @@
+use std::error::Error;
+
+use common::report::{self, Parts, Report};
+use common::Solution;
+
+pub type Solver = fn(usize, &[u8], Parts, usize) -> Result<Report, Box<dyn Error>>;
+
+// Solvers indexed by day, `None` means the day has no solver in code
+pub static SOLVERS: [Option<Solver>; 25] = [
+    Some(solve::<day01::Day01>),
+    Some(solve::<day02::Day02>),
+    Some(solve::<day03::Day03>),
+    Some(solve::<day04::Day04>),
+    Some(solve::<day05::Day05>),
+    Some(solve::<day06::Day06>),
+    Some(solve::<day07::Day07>),
+    Some(solve::<day08::Day08>),
+    Some(solve::<day09::Day09>),
+    Some(solve::<day10::Day10>),
+    Some(solve::<day11::Day11>),
+    Some(solve::<day12::Day12>),
+    Some(solve::<day13::Day13>),
+    Some(solve::<day14::Day14>),
+    Some(solve::<day15::Day15>),
+    Some(solve::<day16::Day16>),
+    Some(solve::<day17::Day17>),
+    Some(solve::<day18::Day18>),
+    Some(solve::<day19::Day19>),
+    Some(solve::<day20::Day20>),
+    Some(solve::<day21::Day21>),
+    Some(solve::<day22::Day22>),
+    Some(solve::<day23::Day23>),
+    Some(solve::<day24::Day24>),
+    Some(solve::<day25::Day25>),
+];
+
+fn solve<S: Solution>(
+    day: usize,
+    input: &[u8],
+    parts: Parts,
+    runs: usize,
+) -> Result<Report, Box<dyn Error>> {
+    Ok(report::measure::<S>(day, input, parts, runs)?)
+}
@@ -0,0 +1,279 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+use rand::rngs::StdRng;
+
+// Random puzzle input along with the answers it's known to have, when the generator knows them
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Generated {
+    pub fn new(input: String, part1: impl ToString, part2: impl ToString) -> Self {
+        Generated {
+            input,
+            answers: [Some(part1.to_string()), Some(part2.to_string())],
+        }
+    }
+
+    // Input whose answers can't be computed without solving the puzzle
+    pub fn unknown(input: String) -> Self {
+        Generated {
+            input,
+            answers: [None, None],
+        }
+    }
+}
+
+// Builds random inputs of a single day, `size` scales the input in a way that depends on the day
+pub struct Generator {
+    pub size: &'static str,
+    pub default_size: usize,
+    pub min_size: usize,
+    pub max_size: usize,
+    pub generate: fn(&mut StdRng, usize) -> Generated,
+}
+
+// Generators indexed by day, the default sizes are close to the official inputs
+pub static GENERATORS: [Generator; 25] = [
+    Generator {
+        size: "number of depth measurements",
+        default_size: 2000,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day01::generate,
+    },
+    Generator {
+        size: "number of commands",
+        default_size: 1000,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day02::generate,
+    },
+    Generator {
+        size: "number of diagnostic numbers",
+        default_size: 1000,
+        min_size: 1,
+        max_size: 1 << 16,
+        generate: day03::generate,
+    },
+    Generator {
+        size: "number of bingo boards",
+        default_size: 100,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day04::generate,
+    },
+    Generator {
+        size: "number of vent lines",
+        default_size: 500,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day05::generate,
+    },
+    Generator {
+        size: "number of lanternfish",
+        default_size: 300,
+        min_size: 1,
+        max_size: 1 << 20,
+        generate: day06::generate,
+    },
+    Generator {
+        size: "number of crabs",
+        default_size: 1000,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day07::generate,
+    },
+    Generator {
+        size: "number of displays",
+        default_size: 200,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day08::generate,
+    },
+    Generator {
+        size: "side of the height map",
+        default_size: 100,
+        min_size: 3,
+        max_size: usize::MAX,
+        generate: day09::generate,
+    },
+    Generator {
+        size: "number of navigation lines",
+        default_size: 100,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day10::generate,
+    },
+    Generator {
+        size: "side of the octopus grid",
+        default_size: 10,
+        min_size: 1,
+        max_size: 20,
+        generate: day11::generate,
+    },
+    Generator {
+        size: "number of small caves",
+        default_size: 6,
+        min_size: 1,
+        max_size: 24,
+        generate: day12::generate,
+    },
+    Generator {
+        size: "number of folds",
+        default_size: 12,
+        min_size: 1,
+        max_size: 20,
+        generate: day13::generate,
+    },
+    Generator {
+        size: "length of the polymer template",
+        default_size: 20,
+        min_size: 10,
+        max_size: usize::MAX,
+        generate: day14::generate,
+    },
+    Generator {
+        size: "side of the risk map",
+        default_size: 100,
+        min_size: 2,
+        max_size: usize::MAX,
+        generate: day15::generate,
+    },
+    Generator {
+        size: "number of packets",
+        default_size: 60,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day16::generate,
+    },
+    Generator {
+        size: "distance to the far edge of the target area",
+        default_size: 250,
+        min_size: 10,
+        max_size: 1 << 16,
+        generate: day17::generate,
+    },
+    Generator {
+        size: "number of snailfish numbers",
+        default_size: 100,
+        min_size: 2,
+        max_size: usize::MAX,
+        generate: day18::generate,
+    },
+    Generator {
+        size: "number of scanners",
+        default_size: 30,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day19::generate,
+    },
+    Generator {
+        size: "side of the input image",
+        default_size: 100,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day20::generate,
+    },
+    Generator {
+        size: "unused, there are always two players",
+        default_size: 1,
+        min_size: 1,
+        max_size: 1,
+        generate: day21::generate,
+    },
+    Generator {
+        size: "number of reboot steps",
+        default_size: 420,
+        min_size: 1,
+        max_size: usize::MAX,
+        generate: day22::generate,
+    },
+    Generator {
+        size: "unused, the burrow always has 4 rooms",
+        default_size: 1,
+        min_size: 1,
+        max_size: 1,
+        generate: day23::generate,
+    },
+    Generator {
+        size: "number of digit pairs in the model number",
+        default_size: 7,
+        min_size: 1,
+        max_size: 9,
+        generate: day24::generate,
+    },
+    Generator {
+        size: "side of the trench",
+        default_size: 140,
+        min_size: 2,
+        max_size: usize::MAX,
+        generate: day25::generate,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::days::SOLVERS;
+    use common::report::Parts;
+
+    // Every solver must agree with the answers the generators built their inputs around
+    #[test]
+    fn known_answers() {
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            let day = i + 1;
+            let Some(solver) = SOLVERS[i] else {
+                continue;
+            };
+
+            for seed in 0..3 {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let generated = (generator.generate)(&mut rng, generator.default_size);
+                let report = solver(day, generated.input.as_bytes(), Parts::new(None), 1)
+                    .unwrap_or_else(|e| panic!("day {}, seed {}: {}", day, seed, e));
+
+                for (part, answer) in [&report.part1, &report.part2].into_iter().enumerate() {
+                    if let Some(expected) = &generated.answers[part] {
+                        assert_eq!(
+                            &answer.as_ref().unwrap().answer,
+                            expected,
+                            "day {}, part {}, seed {}",
+                            day,
+                            part + 1,
+                            seed
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
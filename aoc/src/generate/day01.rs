@@ -0,0 +1,22 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Depths drift deeper with some noise, the way a sonar sweep sees the sea floor
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut depth: u32 = rng.gen_range(100..200);
+    let mut depths = Vec::with_capacity(size);
+    for _ in 0..size {
+        depths.push(depth);
+        depth = depth.saturating_add_signed(rng.gen_range(-10..=20));
+    }
+
+    // Consecutive windows share two measurements, only the outer ones decide
+    let increases = depths.windows(2).filter(|w| w[1] > w[0]).count();
+    let window_increases = depths.windows(4).filter(|w| w[3] > w[0]).count();
+
+    let input = depths.iter().map(|d| format!("{}\n", d)).collect();
+
+    Generated::new(input, increases, window_increases)
+}
@@ -0,0 +1,39 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut input = String::new();
+
+    let (mut horizontal, mut depth1, mut depth2, mut aim) = (0u64, 0u64, 0u64, 0i64);
+    for _ in 0..size {
+        let units: u64 = rng.gen_range(1..=9);
+
+        // Going up is less likely so the submarine ends up deeper
+        match rng.gen_range(0..10) {
+            0..=3 => {
+                input += &format!("forward {}\n", units);
+                horizontal += units;
+                let change = units * aim.unsigned_abs();
+                depth2 = if aim.is_positive() {
+                    depth2 + change
+                } else {
+                    depth2.saturating_sub(change)
+                };
+            }
+            4..=7 => {
+                input += &format!("down {}\n", units);
+                depth1 += units;
+                aim += units as i64;
+            }
+            _ => {
+                input += &format!("up {}\n", units);
+                depth1 = depth1.saturating_sub(units);
+                aim -= units as i64;
+            }
+        }
+    }
+
+    Generated::new(input, horizontal * depth1, horizontal * depth2)
+}
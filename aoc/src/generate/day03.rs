@@ -0,0 +1,60 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use super::Generated;
+
+// Counts how many numbers have each bit set, from the most significant one
+fn count_ones(numbers: &[u32], width: usize) -> Vec<usize> {
+    (0..width)
+        .map(|i| {
+            let mask = 1 << (width - 1 - i);
+            numbers.iter().filter(|&&n| n & mask != 0).count()
+        })
+        .collect()
+}
+
+// Keeps filtering on the bit `keep` picks from the count of ones, until a single number is left.
+// `None` when the numbers left all share the bit that gets filtered out
+fn rating(mut numbers: Vec<u32>, width: usize, keep: fn(usize, usize) -> bool) -> Option<u32> {
+    let mut i = 0;
+    while numbers.len() > 1 {
+        let ones = count_ones(&numbers, width)[i];
+        let mask = 1 << (width - 1 - i);
+        let bit = keep(ones, numbers.len());
+        numbers.retain(|&n| (n & mask != 0) == bit);
+        i += 1;
+    }
+
+    numbers.first().copied()
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // Numbers must be distinct for the ratings to narrow down to one, and ratings fit in 16 bits
+    let width = (usize::BITS - (size - 1).leading_zeros()).clamp(12, 16) as usize;
+    let mut pool: Vec<u32> = (0..1 << width).collect();
+
+    // The least common bit is a 0 even when no number has it, so draw until that never happens
+    let (numbers, oxygen, co2) = loop {
+        pool.shuffle(rng);
+        let numbers = pool[..size].to_vec();
+
+        let oxygen = rating(numbers.clone(), width, |ones, n| ones * 2 >= n);
+        let co2 = rating(numbers.clone(), width, |ones, n| ones * 2 < n);
+        if let (Some(oxygen), Some(co2)) = (oxygen, co2) {
+            break (numbers, oxygen, co2);
+        }
+    };
+
+    // Ties go to ones for the most common bit
+    let gamma = count_ones(&numbers, width)
+        .iter()
+        .fold(0, |acc, &ones| (acc << 1) | (ones * 2 >= size) as u32);
+    let epsilon = !gamma & ((1 << width) - 1);
+
+    let input = numbers
+        .iter()
+        .map(|n| format!("{:0width$b}\n", n, width = width))
+        .collect();
+
+    Generated::new(input, gamma * epsilon, oxygen * co2)
+}
@@ -0,0 +1,80 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use super::Generated;
+
+// Boards are 5x5 with numbers from 0 to 99, and every number ends up drawn so every board wins
+const SIDE: usize = 5;
+const NUMBERS: u8 = 100;
+
+// Index of the draw that completes a row or column of `board`
+fn winning_draw(board: &[u8], draw_order: &[usize]) -> usize {
+    let order = |row: usize, col: usize| draw_order[board[row * SIDE + col] as usize];
+
+    let rows = (0..SIDE).map(|row| (0..SIDE).map(|col| order(row, col)).max().unwrap());
+    let cols = (0..SIDE).map(|col| (0..SIDE).map(|row| order(row, col)).max().unwrap());
+
+    rows.chain(cols).min().unwrap()
+}
+
+fn score(board: &[u8], draws: &[u8], draw: usize, draw_order: &[usize]) -> usize {
+    let unmarked: usize = board
+        .iter()
+        .filter(|&&n| draw_order[n as usize] > draw)
+        .map(|&n| n as usize)
+        .sum();
+
+    unmarked * draws[draw] as usize
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut draws: Vec<u8> = (0..NUMBERS).collect();
+    draws.shuffle(rng);
+
+    let boards: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            let mut numbers: Vec<u8> = (0..NUMBERS).collect();
+            numbers.shuffle(rng);
+            numbers.truncate(SIDE * SIDE);
+            numbers
+        })
+        .collect();
+
+    let mut draw_order = vec![0; NUMBERS as usize];
+    for (i, &n) in draws.iter().enumerate() {
+        draw_order[n as usize] = i;
+    }
+
+    // Boards winning on the same draw are ranked by the order they're listed in, the last one counts
+    let wins: Vec<(usize, usize)> = boards
+        .iter()
+        .enumerate()
+        .map(|(i, board)| (winning_draw(board, &draw_order), i))
+        .collect();
+    let (first_draw, first_board) = *wins
+        .iter()
+        .min_by_key(|&&(draw, i)| (draw, usize::MAX - i))
+        .unwrap();
+    let (last_draw, last_board) = *wins.iter().max().unwrap();
+
+    let mut input = draws
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    input.push('\n');
+    for board in &boards {
+        input.push('\n');
+        for row in board.chunks(SIDE) {
+            let row: Vec<_> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            input += &row.join(" ");
+            input.push('\n');
+        }
+    }
+
+    Generated::new(
+        input,
+        score(&boards[first_board], &draws, first_draw, &draw_order),
+        score(&boards[last_board], &draws, last_draw, &draw_order),
+    )
+}
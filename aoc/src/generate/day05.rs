@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+const EXTENT: i64 = 1000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut straight_overlaps: HashMap<(i64, i64), usize> = HashMap::new();
+    let mut all_overlaps: HashMap<(i64, i64), usize> = HashMap::new();
+
+    for _ in 0..size {
+        // Horizontal, vertical or diagonal at 45 degrees
+        let (dx, dy) = match rng.gen_range(0..3) {
+            0 => (rng.gen_range(0..2) * 2 - 1, 0),
+            1 => (0, rng.gen_range(0..2) * 2 - 1),
+            _ => (rng.gen_range(0..2) * 2 - 1, rng.gen_range(0..2) * 2 - 1),
+        };
+
+        // Pick a length that keeps the line inside the area
+        let (x1, y1) = (rng.gen_range(0..EXTENT), rng.gen_range(0..EXTENT));
+        let room = |v: i64, d: i64| match d {
+            1 => EXTENT - 1 - v,
+            -1 => v,
+            _ => EXTENT,
+        };
+        let max_length = room(x1, dx).min(room(y1, dy)).min(EXTENT / 2);
+        if max_length == 0 {
+            continue;
+        }
+        let length = rng.gen_range(1..=max_length);
+        let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+
+        input += &format!("{},{} -> {},{}\n", x1, y1, x2, y2);
+        for i in 0..=length {
+            let point = (x1 + dx * i, y1 + dy * i);
+            if dx == 0 || dy == 0 {
+                *straight_overlaps.entry(point).or_default() += 1;
+            }
+            *all_overlaps.entry(point).or_default() += 1;
+        }
+    }
+
+    let count = |overlaps: &HashMap<_, usize>| overlaps.values().filter(|&&n| n > 1).count();
+
+    Generated::new(input, count(&straight_overlaps), count(&all_overlaps))
+}
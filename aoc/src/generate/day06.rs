@@ -0,0 +1,28 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Counts the fish after `days` days, tracking how many fish have each timer
+fn simulate(mut timers: [usize; 9], days: usize) -> usize {
+    for _ in 0..days {
+        timers.rotate_left(1);
+        timers[6] += timers[8];
+    }
+
+    timers.iter().sum()
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let fish: Vec<usize> = (0..size).map(|_| rng.gen_range(1..=5)).collect();
+
+    let mut timers = [0; 9];
+    for &timer in &fish {
+        timers[timer] += 1;
+    }
+
+    let fish: Vec<_> = fish.iter().map(|t| t.to_string()).collect();
+    let input = fish.join(",") + "\n";
+
+    Generated::new(input, simulate(timers, 80), simulate(timers, 256))
+}
@@ -0,0 +1,43 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // Most crabs are close to the start, a few are far away
+    let mut positions: Vec<usize> = (0..size)
+        .map(|_| match rng.gen_range(0..4) {
+            0 => rng.gen_range(0..2000),
+            _ => rng.gen_range(0..500),
+        })
+        .collect();
+
+    let input = positions
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+        + "\n";
+
+    // With constant fuel use the median is the cheapest position
+    positions.sort_unstable();
+    let median = positions[size / 2];
+    let constant_fuel: usize = positions.iter().map(|&p| p.abs_diff(median)).sum();
+
+    // With increasing fuel use the cheapest position is less than a step away from the mean
+    let mean = positions.iter().sum::<usize>() / size;
+    let increasing_fuel = (mean.saturating_sub(1)..=mean + 1)
+        .map(|target| {
+            positions
+                .iter()
+                .map(|&p| {
+                    let distance = p.abs_diff(target);
+                    distance * (distance + 1) / 2
+                })
+                .sum::<usize>()
+        })
+        .min()
+        .unwrap();
+
+    Generated::new(input, constant_fuel, increasing_fuel)
+}
@@ -0,0 +1,49 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::Generated;
+
+// Segments lit for each digit on a correctly wired display
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+// Applies the scrambled wiring to a digit, also shuffling the order of its segments
+fn scramble(rng: &mut StdRng, wiring: &[char], digit: usize) -> String {
+    let mut segments: Vec<char> = DIGITS[digit]
+        .chars()
+        .map(|s| wiring[(s as u8 - b'a') as usize])
+        .collect();
+    segments.shuffle(rng);
+
+    segments.into_iter().collect()
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut easy_digits = 0;
+    let mut output_sum = 0;
+
+    for _ in 0..size {
+        let mut wiring: Vec<char> = ('a'..='g').collect();
+        wiring.shuffle(rng);
+
+        let mut patterns: Vec<usize> = (0..10).collect();
+        patterns.shuffle(rng);
+        let output: Vec<usize> = (0..4).map(|_| rng.gen_range(0..10)).collect();
+
+        let patterns: Vec<_> = patterns
+            .iter()
+            .map(|&d| scramble(rng, &wiring, d))
+            .collect();
+        let scrambled_output: Vec<_> = output.iter().map(|&d| scramble(rng, &wiring, d)).collect();
+        input += &format!("{} | {}\n", patterns.join(" "), scrambled_output.join(" "));
+
+        // 1, 4, 7 and 8 are the only digits with a unique number of segments
+        easy_digits += output.iter().filter(|d| [1, 4, 7, 8].contains(d)).count();
+        output_sum += output.iter().fold(0, |acc, d| acc * 10 + d);
+    }
+
+    Generated::new(input, easy_digits, output_sum)
+}
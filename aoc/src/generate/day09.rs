@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Heights rise by one per step away from a basin's low point, and 9 is reached before this far out
+const MAX_RADIUS: usize = 8;
+
+// Builds the map around a set of low points, every basin being walled off by 9s so its size is known
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // Low points are kept apart so walls never land on them
+    let mut low_points: Vec<(usize, usize)> = Vec::new();
+    for _ in 0..(size * size / 20).max(1) {
+        let candidate = (rng.gen_range(0..size), rng.gen_range(0..size));
+        if low_points
+            .iter()
+            .all(|p| p.0.abs_diff(candidate.0) + p.1.abs_diff(candidate.1) >= 4)
+        {
+            low_points.push(candidate);
+        }
+    }
+
+    // Every position belongs to the closest low point
+    let closest = |(row, col): (usize, usize)| {
+        (0..low_points.len())
+            .min_by_key(|&i| low_points[i].0.abs_diff(row) + low_points[i].1.abs_diff(col))
+            .unwrap()
+    };
+    let basins: Vec<Vec<usize>> = (0..size)
+        .map(|row| (0..size).map(|col| closest((row, col))).collect())
+        .collect();
+
+    // Walls go on the side of the basin that was listed last
+    let neighbors = |(row, col): (usize, usize)| {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter_map(move |(dr, dc)| {
+                let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+                (pos.0 < size && pos.1 < size).then_some(pos)
+            })
+    };
+    let is_wall =
+        |pos: (usize, usize)| neighbors(pos).any(|(r, c)| basins[r][c] < basins[pos.0][pos.1]);
+
+    // Spread the heights out from each low point without going through walls
+    let mut heights = vec![vec![9; size]; size];
+    let mut risk_sum = 0;
+    let mut basin_sizes = Vec::new();
+    for (i, &low_point) in low_points.iter().enumerate() {
+        let base = rng.gen_range(0..=3);
+        risk_sum += base + 1;
+
+        let mut basin_size = 0;
+        let mut queue = VecDeque::from([(low_point, 0)]);
+        heights[low_point.0][low_point.1] = base;
+        while let Some((pos, distance)) = queue.pop_front() {
+            basin_size += 1;
+
+            for adj in neighbors(pos) {
+                let height = base + distance + 1;
+                if basins[adj.0][adj.1] == i
+                    && heights[adj.0][adj.1] == 9
+                    && height <= MAX_RADIUS
+                    && adj != low_point
+                    && !is_wall(adj)
+                {
+                    heights[adj.0][adj.1] = height;
+                    queue.push_back((adj, distance + 1));
+                }
+            }
+        }
+        basin_sizes.push(basin_size);
+    }
+
+    basin_sizes.sort_unstable_by(|a, b| b.cmp(a));
+    let largest_basins: usize = basin_sizes.iter().take(3).product();
+
+    let input = heights
+        .iter()
+        .map(|row| row.iter().map(|h| h.to_string()).collect::<String>() + "\n")
+        .collect();
+
+    Generated::new(input, risk_sum, largest_basins)
+}
@@ -0,0 +1,79 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::Generated;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// Illegal character points and completion points, in the same order as `PAIRS`
+const ILLEGAL_POINTS: [usize; 4] = [3, 57, 1197, 25137];
+const COMPLETION_POINTS: [usize; 4] = [1, 2, 3, 4];
+
+// Completion scores grow by a factor of 5 per open chunk, this keeps them well within a usize
+const MAX_OPEN: usize = 20;
+
+// Builds a line of chunks that's left incomplete, returning the indexes of the chunks still open
+fn incomplete_line(rng: &mut StdRng, length: usize) -> (String, Vec<usize>) {
+    let mut line = String::new();
+    let mut open = Vec::new();
+
+    for _ in 0..length {
+        if open.is_empty() || (open.len() < MAX_OPEN && rng.gen_bool(0.55)) {
+            let pair = rng.gen_range(0..PAIRS.len());
+            line.push(PAIRS[pair].0);
+            open.push(pair);
+        } else {
+            line.push(PAIRS[open.pop().unwrap()].1);
+        }
+    }
+
+    // At least one chunk must be left open
+    if open.is_empty() {
+        let pair = rng.gen_range(0..PAIRS.len());
+        line.push(PAIRS[pair].0);
+        open.push(pair);
+    }
+
+    (line, open)
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut syntax_error_score = 0;
+    let mut completion_scores = Vec::new();
+
+    // The middle completion score needs an odd number of incomplete lines
+    let n_incomplete = (size / 2) | 1;
+    let mut kinds: Vec<bool> = (0..size).map(|i| i < n_incomplete).collect();
+    kinds.shuffle(rng);
+
+    for is_incomplete in kinds {
+        let length = rng.gen_range(20..110);
+        let (mut line, open) = incomplete_line(rng, length);
+
+        if is_incomplete {
+            let score = open
+                .iter()
+                .rev()
+                .fold(0, |acc, &pair| acc * 5 + COMPLETION_POINTS[pair]);
+            completion_scores.push(score);
+        } else {
+            // Close the innermost chunk with the wrong character, then keep going
+            let expected = *open.last().unwrap();
+            let wrong = (expected + rng.gen_range(1..PAIRS.len())) % PAIRS.len();
+            line.push(PAIRS[wrong].1);
+            let tail_length = rng.gen_range(0..20);
+            line += &incomplete_line(rng, tail_length).0;
+            syntax_error_score += ILLEGAL_POINTS[wrong];
+        }
+
+        input += &line;
+        input.push('\n');
+    }
+
+    completion_scores.sort_unstable();
+    let middle_score = completion_scores[completion_scores.len() / 2];
+
+    Generated::new(input, syntax_error_score, middle_score)
+}
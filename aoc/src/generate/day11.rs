@@ -0,0 +1,67 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Grids that don't synchronize within this many steps are discarded, most never do
+const MAX_STEPS: usize = 2_000;
+
+// Runs a single step on the `size` x `size` grid, returning the number of flashes
+fn step(levels: &mut [u8], size: usize) -> usize {
+    let mut flashing: Vec<usize> = Vec::new();
+    for (i, level) in levels.iter_mut().enumerate() {
+        *level += 1;
+        if *level > 9 {
+            flashing.push(i);
+        }
+    }
+
+    let mut flashes = 0;
+    while let Some(i) = flashing.pop() {
+        levels[i] = 0;
+        flashes += 1;
+
+        let (row, col) = (i / size, i % size);
+        for r in row.saturating_sub(1)..=(row + 1).min(size - 1) {
+            for c in col.saturating_sub(1)..=(col + 1).min(size - 1) {
+                let j = r * size + c;
+
+                // Octopi that flashed this step stay at 0
+                if levels[j] > 0 {
+                    levels[j] += 1;
+                    if levels[j] == 10 {
+                        flashing.push(j);
+                    }
+                }
+            }
+        }
+    }
+
+    flashes
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // The solver waits for the octopi to synchronize forever, so only keep grids where they do
+    let (levels, synchronized) = loop {
+        let levels: Vec<u8> = (0..size * size).map(|_| rng.gen_range(0..=9)).collect();
+
+        let mut simulated = levels.clone();
+        let synchronized = (1..=MAX_STEPS).find(|_| {
+            step(&mut simulated, size);
+            simulated.iter().all(|&l| l == 0)
+        });
+        if let Some(synchronized) = synchronized {
+            break (levels, synchronized);
+        }
+    };
+
+    let input = levels
+        .chunks(size)
+        .map(|row| row.iter().map(|l| l.to_string()).collect::<String>() + "\n")
+        .collect();
+
+    let mut simulated = levels;
+    let flashes: usize = (0..100).map(|_| step(&mut simulated, size)).sum();
+
+    Generated::new(input, flashes, synchronized)
+}
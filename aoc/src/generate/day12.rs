@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::Generated;
+
+const START: usize = 0;
+const END: usize = 1;
+
+struct Caves {
+    n_small: usize,
+    connections: Vec<Vec<usize>>,
+}
+
+impl Caves {
+    // Small caves come right after start and end
+    fn small_bit(&self, cave: usize) -> Option<u32> {
+        (2..2 + self.n_small)
+            .contains(&cave)
+            .then(|| 1 << (cave - 2))
+    }
+
+    // Counts the paths from `cave` to the end, given the small caves already visited
+    fn count_paths(
+        &self,
+        cave: usize,
+        visited: u32,
+        can_revisit: bool,
+        memo: &mut HashMap<(usize, u32, bool), usize>,
+    ) -> usize {
+        if let Some(&count) = memo.get(&(cave, visited, can_revisit)) {
+            return count;
+        }
+
+        let mut count = 0;
+        for &next in &self.connections[cave] {
+            count += match (next, self.small_bit(next)) {
+                (START, _) => 0,
+                (END, _) => 1,
+                (_, Some(bit)) if visited & bit == 0 => {
+                    self.count_paths(next, visited | bit, can_revisit, memo)
+                }
+                (_, Some(_)) if can_revisit => self.count_paths(next, visited, false, memo),
+                (_, Some(_)) => 0,
+                (_, None) => self.count_paths(next, visited, can_revisit, memo),
+            };
+        }
+
+        memo.insert((cave, visited, can_revisit), count);
+        count
+    }
+}
+
+// Two letter names, lowercase for small caves and uppercase for big ones
+fn random_names(rng: &mut StdRng, count: usize, letters: &[u8]) -> Vec<String> {
+    let mut names: Vec<String> = letters
+        .iter()
+        .flat_map(|&a| {
+            letters
+                .iter()
+                .map(move |&b| String::from_utf8(vec![a, b]).unwrap())
+        })
+        .collect();
+    names.shuffle(rng);
+    names.truncate(count);
+
+    names
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let n_big = (size / 2).max(1);
+    let lowercase: Vec<u8> = (b'a'..=b'z').collect();
+    let uppercase: Vec<u8> = (b'A'..=b'Z').collect();
+
+    let mut names = vec!["start".to_string(), "end".to_string()];
+    names.extend(random_names(rng, size, &lowercase));
+    names.extend(random_names(rng, n_big, &uppercase));
+    let small: Vec<usize> = (2..2 + size).collect();
+    let big: Vec<usize> = (2 + size..2 + size + n_big).collect();
+
+    // Big caves are never connected to each other, or paths could loop forever
+    let mut edges = Vec::new();
+    for &b in &big {
+        let mut targets: Vec<usize> = [START, END].into_iter().chain(small.clone()).collect();
+        targets.shuffle(rng);
+        let n_targets = rng.gen_range(2..=4).min(targets.len());
+        edges.extend(targets[..n_targets].iter().map(|&t| (b, t)));
+    }
+    for (i, &a) in small.iter().enumerate() {
+        for &b in &small[i + 1..] {
+            if rng.gen_bool(0.3) {
+                edges.push((a, b));
+            }
+        }
+    }
+    for cave in [START, END] {
+        let mut targets = small.clone();
+        targets.shuffle(rng);
+        edges.extend(
+            targets
+                .iter()
+                .take(rng.gen_range(1..=2))
+                .map(|&t| (cave, t)),
+        );
+    }
+    edges.sort_unstable_by_key(|&(a, b)| (a.min(b), a.max(b)));
+    edges.dedup_by_key(|&mut (a, b)| (a.min(b), a.max(b)));
+    edges.shuffle(rng);
+
+    let mut connections = vec![Vec::new(); names.len()];
+    let mut input = String::new();
+    for &(a, b) in &edges {
+        connections[a].push(b);
+        connections[b].push(a);
+
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        input += &format!("{}-{}\n", names[a], names[b]);
+    }
+
+    let caves = Caves {
+        n_small: size,
+        connections,
+    };
+
+    Generated::new(
+        input,
+        caves.count_paths(START, 0, false, &mut HashMap::new()),
+        caves.count_paths(START, 0, true, &mut HashMap::new()),
+    )
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::Generated;
+
+// Picks a random code to show once folded, then unfolds it `size` times
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let (mut width, mut height) = (rng.gen_range(30..=40), rng.gen_range(6..=8));
+
+    let code: HashSet<(usize, usize)> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|_| rng.gen_bool(0.35))
+        .collect();
+    let mut rows = vec![vec![' '; width]; height];
+    for &(x, y) in &code {
+        rows[y][x] = '#';
+    }
+    let code_str = rows
+        .into_iter()
+        .map(|r| r.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Each unfold mirrors a dot to the other side of the fold line, keeps it where it is, or both
+    let mut dots = code;
+    let mut dots_before_last_unfold = 0;
+    let mut folds = Vec::new();
+    for _ in 0..size {
+        let along_x = rng.gen_bool(0.5);
+        let line = if along_x { width } else { height };
+        let mirror = |v: usize| 2 * line - v;
+
+        dots_before_last_unfold = dots.len();
+        dots = dots
+            .into_iter()
+            .flat_map(|(x, y)| {
+                let mirrored = if along_x {
+                    (mirror(x), y)
+                } else {
+                    (x, mirror(y))
+                };
+                match rng.gen_range(0..5) {
+                    0 | 1 => vec![(x, y)],
+                    2 | 3 => vec![mirrored],
+                    _ => vec![(x, y), mirrored],
+                }
+            })
+            .collect();
+
+        if along_x {
+            folds.push(format!("fold along x={}", line));
+            width = 2 * line + 1;
+        } else {
+            folds.push(format!("fold along y={}", line));
+            height = 2 * line + 1;
+        }
+    }
+
+    let mut dots: Vec<_> = dots.into_iter().collect();
+    dots.shuffle(rng);
+
+    let mut input: String = dots.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+    input.push('\n');
+    for fold in folds.iter().rev() {
+        input += fold;
+        input.push('\n');
+    }
+
+    // The first fold undoes the last unfold
+    Generated::new(input, dots_before_last_unfold, code_str)
+}
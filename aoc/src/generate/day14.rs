@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use super::Generated;
+
+const ELEMENTS: &[u8] = b"BCFHKNOPSV";
+
+// Difference between the most and least common elements after `steps` steps
+fn simulate(template: &[u8], rules: &HashMap<(u8, u8), u8>, steps: usize) -> usize {
+    let mut pairs: HashMap<(u8, u8), usize> = HashMap::new();
+    for w in template.windows(2) {
+        *pairs.entry((w[0], w[1])).or_default() += 1;
+    }
+
+    for _ in 0..steps {
+        let mut next = HashMap::new();
+        for (&(a, b), &count) in &pairs {
+            let inserted = rules[&(a, b)];
+            *next.entry((a, inserted)).or_default() += count;
+            *next.entry((inserted, b)).or_default() += count;
+        }
+        pairs = next;
+    }
+
+    // Every element is the first of a pair, except for the last one of the template
+    let mut counts: HashMap<u8, usize> = HashMap::new();
+    for (&(a, _), &count) in &pairs {
+        *counts.entry(a).or_default() += count;
+    }
+    *counts.entry(*template.last().unwrap()).or_default() += 1;
+
+    counts.values().max().unwrap() - counts.values().min().unwrap()
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // Every element shows up in the template so none of them has a count of 0
+    let mut template = ELEMENTS.to_vec();
+    template.extend((ELEMENTS.len()..size).map(|_| *ELEMENTS.choose(rng).unwrap()));
+    template.shuffle(rng);
+
+    let mut rules = HashMap::new();
+    let mut input = String::from_utf8(template.clone()).unwrap() + "\n\n";
+    for &a in ELEMENTS {
+        for &b in ELEMENTS {
+            let inserted = *ELEMENTS.choose(rng).unwrap();
+            rules.insert((a, b), inserted);
+            input += &format!("{}{} -> {}\n", a as char, b as char, inserted as char);
+        }
+    }
+
+    Generated::new(
+        input,
+        simulate(&template, &rules, 10),
+        simulate(&template, &rules, 40),
+    )
+}
@@ -0,0 +1,64 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Lowest total risk from the top left to the bottom right corner, `risk` being the risk of a position
+fn lowest_risk(side: usize, risk: impl Fn(usize, usize) -> usize) -> usize {
+    let mut best = vec![usize::MAX; side * side];
+    let mut queue = BinaryHeap::from([Reverse((0, 0, 0))]);
+    best[0] = 0;
+
+    while let Some(Reverse((total, row, col))) = queue.pop() {
+        if (row, col) == (side - 1, side - 1) {
+            return total;
+        }
+        if total > best[row * side + col] {
+            continue;
+        }
+
+        let adjacent = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+        for (r, c) in adjacent {
+            if r < side && c < side {
+                let next = total + risk(r, c);
+                if next < best[r * side + c] {
+                    best[r * side + c] = next;
+                    queue.push(Reverse((next, r, c)));
+                }
+            }
+        }
+    }
+
+    unreachable!("the grid is connected")
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let risks: Vec<usize> = (0..size * size).map(|_| rng.gen_range(1..=9)).collect();
+
+    let input = risks
+        .chunks(size)
+        .map(|row| row.iter().map(|r| r.to_string()).collect::<String>() + "\n")
+        .collect();
+
+    let risk = |row: usize, col: usize| risks[row * size + col];
+
+    // The full map repeats 5 times in each direction, risks going up by one per repetition
+    let full_risk = |row: usize, col: usize| {
+        let tile = row / size + col / size;
+        (risk(row % size, col % size) + tile - 1) % 9 + 1
+    };
+
+    Generated::new(
+        input,
+        lowest_risk(size, risk),
+        lowest_risk(size * 5, full_risk),
+    )
+}
@@ -0,0 +1,167 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Operator values are kept below this so they never overflow
+const MAX_VALUE: u64 = 1 << 48;
+
+enum Packet {
+    Literal {
+        version: u8,
+        value: u64,
+    },
+    Operator {
+        version: u8,
+        type_id: u8,
+        sub_packets: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    fn version_sum(&self) -> u64 {
+        match self {
+            Self::Literal { version, .. } => *version as u64,
+            Self::Operator {
+                version,
+                sub_packets,
+                ..
+            } => *version as u64 + sub_packets.iter().map(Self::version_sum).sum::<u64>(),
+        }
+    }
+
+    // `None` when the value would get too large
+    fn value(&self) -> Option<u64> {
+        let (type_id, sub_packets) = match self {
+            Self::Literal { value, .. } => return Some(*value),
+            Self::Operator {
+                type_id,
+                sub_packets,
+                ..
+            } => (type_id, sub_packets),
+        };
+
+        let values: Vec<u64> = sub_packets.iter().map(Self::value).collect::<Option<_>>()?;
+        let value = match type_id {
+            0 => values.iter().try_fold(0u64, |acc, &v| acc.checked_add(v))?,
+            1 => values.iter().try_fold(1u64, |acc, &v| acc.checked_mul(v))?,
+            2 => *values.iter().min()?,
+            3 => *values.iter().max()?,
+            5 => (values[0] > values[1]) as u64,
+            6 => (values[0] < values[1]) as u64,
+            _ => (values[0] == values[1]) as u64,
+        };
+
+        (value < MAX_VALUE).then_some(value)
+    }
+
+    fn push_bits(bits: &mut Vec<bool>, value: u64, len: usize) {
+        bits.extend((0..len).rev().map(|i| value >> i & 1 == 1));
+    }
+
+    fn encode(&self, rng: &mut StdRng, bits: &mut Vec<bool>) {
+        match self {
+            Self::Literal { version, value } => {
+                Self::push_bits(bits, *version as u64, 3);
+                Self::push_bits(bits, 4, 3);
+
+                // Groups of 4 bits, each one but the last prefixed by a 1
+                let n_groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+                for group in (0..n_groups).rev() {
+                    bits.push(group > 0);
+                    Self::push_bits(bits, value >> (group * 4) & 0xF, 4);
+                }
+            }
+            Self::Operator {
+                version,
+                type_id,
+                sub_packets,
+            } => {
+                Self::push_bits(bits, *version as u64, 3);
+                Self::push_bits(bits, *type_id as u64, 3);
+
+                let mut sub_bits = Vec::new();
+                for sub_packet in sub_packets {
+                    sub_packet.encode(rng, &mut sub_bits);
+                }
+
+                // Either length type works as long as the length fits in its field
+                if sub_bits.len() < 1 << 15 && rng.gen_bool(0.5) {
+                    bits.push(false);
+                    Self::push_bits(bits, sub_bits.len() as u64, 15);
+                } else {
+                    bits.push(true);
+                    Self::push_bits(bits, sub_packets.len() as u64, 11);
+                }
+                bits.extend(sub_bits);
+            }
+        }
+    }
+}
+
+// Builds a packet made of `size` packets in total
+fn random_packet(rng: &mut StdRng, size: usize) -> Packet {
+    let version = rng.gen_range(0..8);
+
+    if size == 1 {
+        let value = match rng.gen_range(0..4) {
+            0 => rng.gen_range(0..1 << 32),
+            _ => rng.gen_range(0..1000),
+        };
+        return Packet::Literal { version, value };
+    }
+
+    // Comparisons always have two sub-packets
+    let remaining = size - 1;
+    let type_id = match rng.gen_range(0..7) {
+        t @ 0..=3 => t,
+        t if remaining >= 2 => t + 1,
+        _ => rng.gen_range(0..=3),
+    };
+    let n_sub_packets = match type_id {
+        5..=7 => 2,
+        _ => rng.gen_range(1..=remaining.min(5)),
+    };
+
+    // Share the remaining packets out, every sub-packet getting at least one
+    let mut sizes = vec![1; n_sub_packets];
+    for _ in n_sub_packets..remaining {
+        sizes[rng.gen_range(0..n_sub_packets)] += 1;
+    }
+    let sub_packets = sizes.into_iter().map(|s| random_packet(rng, s)).collect();
+
+    let mut packet = Packet::Operator {
+        version,
+        type_id,
+        sub_packets,
+    };
+
+    // Sums and products can get too large, the minimum never is
+    if packet.value().is_none() {
+        if let Packet::Operator { type_id, .. } = &mut packet {
+            *type_id = 2;
+        }
+    }
+
+    packet
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let packet = random_packet(rng, size);
+
+    let mut bits = Vec::new();
+    packet.encode(rng, &mut bits);
+
+    // The transmission is written in hexadecimal, padded with zeros
+    bits.resize(bits.len().div_ceil(4) * 4, false);
+    let mut input: String = bits
+        .chunks(4)
+        .map(|nibble| {
+            let digit = nibble.iter().fold(0, |acc, &b| acc << 1 | b as u32);
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+    input.push('\n');
+
+    Generated::new(input, packet.version_sum(), packet.value().unwrap())
+}
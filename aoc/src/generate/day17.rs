@@ -0,0 +1,46 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+fn triangular(n: isize) -> isize {
+    n * (n + 1) / 2
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let size = size as isize;
+
+    // Some x velocity must stall above the target, the highest shots fall straight down into it
+    let max_n = (1..).take_while(|&n| triangular(n) <= size).last().unwrap();
+    let stall_x = triangular(rng.gen_range((max_n + 1) / 2..=max_n));
+    let x1 = rng.gen_range((stall_x - size / 5).max(1)..=stall_x);
+    let x2 = rng.gen_range(stall_x..=size);
+
+    let y1 = -rng.gen_range(size / 4..=size / 2).max(max_n);
+    let y2 = rng.gen_range(y1..=y1 / 2).min(-1);
+
+    let input = format!("target area: x={}..{}, y={}..{}\n", x1, x2, y1, y2);
+
+    let mut highest_peak = 0;
+    let mut hits = 0;
+    for start_vx in 1..=x2 {
+        for start_vy in y1..=-y1 {
+            let (mut x, mut y) = (0, 0);
+            let (mut vx, mut vy) = (start_vx, start_vy);
+            while x <= x2 && y >= y1 {
+                x += vx;
+                y += vy;
+                vx -= vx.signum();
+                vy -= 1;
+
+                if (x1..=x2).contains(&x) && (y1..=y2).contains(&y) {
+                    highest_peak = highest_peak.max(triangular(start_vy.max(0)));
+                    hits += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    Generated::new(input, highest_peak, hits)
+}
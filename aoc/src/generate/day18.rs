@@ -0,0 +1,93 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Regular numbers of a snailfish number from left to right, along with how many pairs hold them
+type Flat = Vec<(u32, u32)>;
+
+// Writes a random number nested at most 4 pairs deep, returning its flat form too
+fn random_number(rng: &mut StdRng, depth: u32, s: &mut String, flat: &mut Flat) {
+    if depth > 1 && (depth > 4 || rng.gen_bool(0.4)) {
+        let value = rng.gen_range(0..10);
+        s.push(char::from_digit(value, 10).unwrap());
+        flat.push((value, depth - 1));
+        return;
+    }
+
+    s.push('[');
+    random_number(rng, depth + 1, s, flat);
+    s.push(',');
+    random_number(rng, depth + 1, s, flat);
+    s.push(']');
+}
+
+fn add(a: &Flat, b: &Flat) -> Flat {
+    let mut sum: Flat = a.iter().chain(b).map(|&(v, d)| (v, d + 1)).collect();
+
+    loop {
+        // The leftmost pair nested inside four pairs explodes
+        if let Some(i) = sum.iter().position(|&(_, d)| d > 4) {
+            let (left, right) = (sum[i].0, sum[i + 1].0);
+            if i > 0 {
+                sum[i - 1].0 += left;
+            }
+            if i + 2 < sum.len() {
+                sum[i + 2].0 += right;
+            }
+            sum.splice(i..i + 2, [(0, sum[i].1 - 1)]);
+            continue;
+        }
+
+        // Otherwise the leftmost number of 10 or more splits
+        if let Some(i) = sum.iter().position(|&(v, _)| v >= 10) {
+            let (v, d) = sum[i];
+            sum.splice(i..=i, [(v / 2, d + 1), (v.div_ceil(2), d + 1)]);
+            continue;
+        }
+
+        return sum;
+    }
+}
+
+fn magnitude(number: &Flat) -> u32 {
+    let mut number = number.clone();
+
+    // The leftmost two numbers at the deepest level always form a pair
+    while number.len() > 1 {
+        let max_depth = number.iter().map(|&(_, d)| d).max().unwrap();
+        let i = number.iter().position(|&(_, d)| d == max_depth).unwrap();
+        number.splice(
+            i..i + 2,
+            [(3 * number[i].0 + 2 * number[i + 1].0, max_depth - 1)],
+        );
+    }
+
+    number[0].0
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut numbers = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut flat = Vec::new();
+        random_number(rng, 1, &mut input, &mut flat);
+        input.push('\n');
+        numbers.push(flat);
+    }
+
+    let total = numbers[1..]
+        .iter()
+        .fold(numbers[0].clone(), |acc, n| add(&acc, n));
+
+    let mut largest = 0;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                largest = largest.max(magnitude(&add(a, b)));
+            }
+        }
+    }
+
+    Generated::new(input, magnitude(&total), largest)
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::Generated;
+
+type Vector = [i64; 3];
+
+// Scanners see beacons at most this far away along every axis
+const RANGE: i64 = 1000;
+// Scanners are placed at most this far from the one they overlap with, along every axis
+const MAX_OFFSET: i64 = 1100;
+// but at least this far from every other scanner along some axis, so they don't all see the same beacons
+const MIN_DISTANCE: i64 = 1000;
+// Beacons two overlapping scanners must both see
+const OVERLAP: usize = 12;
+
+// The 24 rotations are the signed permutation matrices that don't mirror
+fn rotations() -> Vec<[Vector; 3]> {
+    let permutations = [
+        ([0, 1, 2], 1),
+        ([1, 2, 0], 1),
+        ([2, 0, 1], 1),
+        ([0, 2, 1], -1),
+        ([2, 1, 0], -1),
+        ([1, 0, 2], -1),
+    ];
+
+    let mut rotations = Vec::with_capacity(24);
+    for (axes, parity) in permutations {
+        for signs in 0..8 {
+            let sign = |i: usize| if signs >> i & 1 == 1 { -1 } else { 1 };
+            if parity * sign(0) * sign(1) * sign(2) != 1 {
+                continue;
+            }
+
+            let mut matrix = [[0; 3]; 3];
+            for (row, &axis) in axes.iter().enumerate() {
+                matrix[row][axis] = sign(row);
+            }
+            rotations.push(matrix);
+        }
+    }
+
+    rotations
+}
+
+fn random_point(rng: &mut StdRng, min: Vector, max: Vector) -> Vector {
+    [0, 1, 2].map(|i| rng.gen_range(min[i]..=max[i]))
+}
+
+fn sees(scanner: Vector, beacon: Vector) -> bool {
+    (0..3).all(|i| (beacon[i] - scanner[i]).abs() <= RANGE)
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut scanners: Vec<Vector> = vec![[0; 3]];
+    let mut beacons = HashSet::new();
+
+    let cube = |scanner: Vector| (scanner.map(|c| c - RANGE), scanner.map(|c| c + RANGE));
+
+    let (min, max) = cube(scanners[0]);
+    while beacons.len() < rng.gen_range(20..=30) {
+        beacons.insert(random_point(rng, min, max));
+    }
+
+    // Every scanner overlaps with one placed before it, so they can all be aligned with scanner 0
+    for _ in 1..size {
+        let (parent, scanner) = loop {
+            let parent = *scanners.choose(rng).unwrap();
+            let scanner = parent.map(|c| c + rng.gen_range(-MAX_OFFSET..=MAX_OFFSET));
+
+            let distance = |s: &Vector| (0..3).map(|i| (s[i] - scanner[i]).abs()).max().unwrap();
+            if scanners.iter().all(|s| distance(s) >= MIN_DISTANCE) {
+                break (parent, scanner);
+            }
+        };
+
+        let min = [0, 1, 2].map(|i| parent[i].max(scanner[i]) - RANGE);
+        let max = [0, 1, 2].map(|i| parent[i].min(scanner[i]) + RANGE);
+        let shared = OVERLAP + rng.gen_range(0..4);
+        while beacons
+            .iter()
+            .filter(|&&b| sees(parent, b) && sees(scanner, b))
+            .count()
+            < shared
+        {
+            beacons.insert(random_point(rng, min, max));
+        }
+
+        let (min, max) = cube(scanner);
+        for _ in 0..rng.gen_range(0..=6) {
+            beacons.insert(random_point(rng, min, max));
+        }
+
+        scanners.push(scanner);
+    }
+
+    // Scanner 0 keeps the reference orientation
+    let rotations = rotations();
+    let mut input = String::new();
+    for (i, &scanner) in scanners.iter().enumerate() {
+        let rotation = if i == 0 {
+            rotations[0]
+        } else {
+            *rotations.choose(rng).unwrap()
+        };
+
+        let mut seen: Vec<Vector> = beacons
+            .iter()
+            .filter(|&&b| sees(scanner, b))
+            .map(|b| {
+                let relative = [0, 1, 2].map(|j| b[j] - scanner[j]);
+                rotation.map(|row| (0..3).map(|j| row[j] * relative[j]).sum())
+            })
+            .collect();
+        seen.shuffle(rng);
+
+        if i > 0 {
+            input.push('\n');
+        }
+        input += &format!("--- scanner {} ---\n", i);
+        for [x, y, z] in seen {
+            input += &format!("{},{},{}\n", x, y, z);
+        }
+    }
+
+    let mut largest_distance = 0;
+    for a in &scanners {
+        for b in &scanners {
+            largest_distance = largest_distance.max((0..3).map(|i| (a[i] - b[i]).abs()).sum());
+        }
+    }
+
+    Generated::new(input, beacons.len(), largest_distance)
+}
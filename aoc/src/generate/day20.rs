@@ -0,0 +1,92 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Image on an infinite plane, every pixel outside the grid is `infinity`
+struct Image {
+    pixels: Vec<Vec<bool>>,
+    infinity: bool,
+}
+
+impl Image {
+    fn get(&self, row: isize, col: isize) -> bool {
+        if row < 0 || col < 0 {
+            return self.infinity;
+        }
+        self.pixels
+            .get(row as usize)
+            .and_then(|r| r.get(col as usize))
+            .copied()
+            .unwrap_or(self.infinity)
+    }
+
+    // The image grows by one pixel on every side
+    fn enhance(&self, algorithm: &[bool]) -> Self {
+        let height = self.pixels.len() as isize;
+        let width = self.pixels[0].len() as isize;
+
+        let pixels = (-1..=height)
+            .map(|row| {
+                (-1..=width)
+                    .map(|col| {
+                        let mut index = 0;
+                        for dr in -1..=1 {
+                            for dc in -1..=1 {
+                                index = index << 1 | self.get(row + dr, col + dc) as usize;
+                            }
+                        }
+                        algorithm[index]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let infinity = algorithm[if self.infinity { 511 } else { 0 }];
+
+        Image { pixels, infinity }
+    }
+
+    fn lit(&self) -> usize {
+        self.pixels.iter().flatten().filter(|&&p| p).count()
+    }
+}
+
+fn render(pixels: &[bool]) -> String {
+    pixels.iter().map(|&p| if p { '#' } else { '.' }).collect()
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // Lighting up the whole plane is only fine if it goes dark again on the next step
+    let mut algorithm: Vec<bool> = (0..512).map(|_| rng.gen_bool(0.5)).collect();
+    if algorithm[0] {
+        algorithm[511] = false;
+    }
+
+    let pixels: Vec<Vec<bool>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_bool(0.5)).collect())
+        .collect();
+
+    let mut input = render(&algorithm);
+    input += "\n\n";
+    for row in &pixels {
+        input += &render(row);
+        input.push('\n');
+    }
+
+    let mut image = Image {
+        pixels,
+        infinity: false,
+    };
+    let mut lit = [0; 2];
+    for step in 1..=50 {
+        image = image.enhance(&algorithm);
+        match step {
+            2 => lit[0] = image.lit(),
+            50 => lit[1] = image.lit(),
+            _ => (),
+        }
+    }
+
+    Generated::new(input, lit[0], lit[1])
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+const WINNING_SCORE: usize = 1000;
+const DIRAC_WINNING_SCORE: usize = 21;
+
+fn advance(position: usize, steps: usize) -> usize {
+    (position + steps - 1) % 10 + 1
+}
+
+fn deterministic(mut positions: [usize; 2]) -> usize {
+    let mut scores = [0; 2];
+    let mut rolls = 0;
+
+    for player in (0..2).cycle() {
+        let steps = (0..3).map(|i| (rolls + i) % 100 + 1).sum();
+        rolls += 3;
+
+        positions[player] = advance(positions[player], steps);
+        scores[player] += positions[player];
+        if scores[player] >= WINNING_SCORE {
+            return scores[1 - player] * rolls;
+        }
+    }
+
+    unreachable!()
+}
+
+// Universes where the player about to move wins and where the other one does
+fn dirac(
+    positions: [usize; 2],
+    scores: [usize; 2],
+    cache: &mut HashMap<([usize; 2], [usize; 2]), [usize; 2]>,
+) -> [usize; 2] {
+    if let Some(&wins) = cache.get(&(positions, scores)) {
+        return wins;
+    }
+
+    // How many of the 27 universes roll each total
+    const TOTALS: [(usize, usize); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+    let mut wins = [0; 2];
+    for (steps, universes) in TOTALS {
+        let position = advance(positions[0], steps);
+        let score = scores[0] + position;
+        if score >= DIRAC_WINNING_SCORE {
+            wins[0] += universes;
+        } else {
+            let [other, current] = dirac([positions[1], position], [scores[1], score], cache);
+            wins[0] += universes * current;
+            wins[1] += universes * other;
+        }
+    }
+
+    cache.insert((positions, scores), wins);
+    wins
+}
+
+pub fn generate(rng: &mut StdRng, _size: usize) -> Generated {
+    let positions = [rng.gen_range(1..=10), rng.gen_range(1..=10)];
+
+    let input = format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        positions[0], positions[1]
+    );
+
+    let wins = dirac(positions, [0; 2], &mut HashMap::new());
+
+    Generated::new(input, deterministic(positions), wins[0].max(wins[1]))
+}
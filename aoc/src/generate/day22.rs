@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Steps of the initialization procedure stay within this distance of the origin
+const INIT_REGION: i64 = 50;
+const REBOOT_REGION: i64 = 100_000;
+const INIT_STEPS: usize = 20;
+
+type Cuboid = [(i64, i64); 3];
+
+fn random_cuboid(rng: &mut StdRng, region: i64, min_side: i64, max_side: i64) -> Cuboid {
+    [0; 3].map(|_| {
+        let side = rng.gen_range(min_side..=max_side);
+        let start = rng.gen_range(-region..=region - side);
+        (start, start + side)
+    })
+}
+
+fn intersection(a: &Cuboid, b: &Cuboid) -> Option<Cuboid> {
+    let mut cuboid = [(0, 0); 3];
+    for i in 0..3 {
+        cuboid[i] = (a[i].0.max(b[i].0), a[i].1.min(b[i].1));
+        if cuboid[i].0 > cuboid[i].1 {
+            return None;
+        }
+    }
+
+    Some(cuboid)
+}
+
+fn volume(cuboid: &Cuboid) -> i64 {
+    cuboid.iter().map(|(start, end)| end - start + 1).product()
+}
+
+// Cubes left on, counted by adding and subtracting the cuboids overlaps
+fn count_on(steps: &[(bool, Cuboid)]) -> i64 {
+    let mut signed: HashMap<Cuboid, i64> = HashMap::new();
+    for (on, cuboid) in steps {
+        let mut updates: HashMap<Cuboid, i64> = HashMap::new();
+        for (other, &sign) in &signed {
+            if let Some(overlap) = intersection(cuboid, other) {
+                *updates.entry(overlap).or_default() -= sign;
+            }
+        }
+        if *on {
+            *updates.entry(*cuboid).or_default() += 1;
+        }
+
+        for (cuboid, sign) in updates {
+            *signed.entry(cuboid).or_default() += sign;
+        }
+        signed.retain(|_, sign| *sign != 0);
+    }
+
+    signed
+        .iter()
+        .map(|(cuboid, sign)| volume(cuboid) * sign)
+        .sum()
+}
+
+fn format_step((on, cuboid): &(bool, Cuboid)) -> String {
+    format!(
+        "{} x={}..{},y={}..{},z={}..{}\n",
+        if *on { "on" } else { "off" },
+        cuboid[0].0,
+        cuboid[0].1,
+        cuboid[1].0,
+        cuboid[1].1,
+        cuboid[2].0,
+        cuboid[2].1,
+    )
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // The initialization steps come first, they are run before the rest even if they weren't
+    let n_init = size.min(INIT_STEPS);
+    let steps: Vec<(bool, Cuboid)> = (0..size)
+        .map(|i| {
+            let on = i == 0 || rng.gen_bool(0.6);
+            let cuboid = if i < n_init {
+                random_cuboid(rng, INIT_REGION, 10, 40)
+            } else {
+                random_cuboid(rng, REBOOT_REGION, 10_000, 60_000)
+            };
+            (on, cuboid)
+        })
+        .collect();
+
+    let input: String = steps.iter().map(format_step).collect();
+
+    Generated::new(input, count_on(&steps[..n_init]), count_on(&steps))
+}
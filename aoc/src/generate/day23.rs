@@ -0,0 +1,27 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use super::Generated;
+
+pub fn generate(rng: &mut StdRng, _size: usize) -> Generated {
+    let mut amphipods = *b"AABBCCDD";
+    while amphipods == *b"ABCDABCD" || amphipods == *b"AABBCCDD" {
+        amphipods.shuffle(rng);
+    }
+    let row = |i: usize| {
+        amphipods[i..i + 4]
+            .iter()
+            .map(|&a| (a as char).to_string())
+            .collect::<Vec<_>>()
+            .join("#")
+    };
+
+    let input = format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(0),
+        row(4)
+    );
+
+    // Solving the burrow is the whole puzzle
+    Generated::unknown(input)
+}
@@ -0,0 +1,47 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Every digit is read by a copy of this block, differing only in the three parameters
+fn block(div_z: i64, add_x: i64, add_y: i64) -> String {
+    format!(
+        "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\n\
+         add y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+        div_z, add_x, add_y
+    )
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let n_digits = 2 * size;
+
+    // Blocks either push their digit onto z as a base 26 stack or pop the digit of a previous block,
+    // and z only ends up at 0 if every popping digit matches the digit it pops
+    let mut input = String::new();
+    let mut largest = vec![0; n_digits];
+    let mut smallest = vec![0; n_digits];
+    let mut stack: Vec<(usize, i64)> = Vec::new();
+    let mut pushes_left = size;
+    for digit in 0..n_digits {
+        if pushes_left > 0 && (stack.is_empty() || rng.gen_bool(0.5)) {
+            let add_y = rng.gen_range(1..=16);
+            input += &block(1, rng.gen_range(10..=15), add_y);
+            stack.push((digit, add_y));
+            pushes_left -= 1;
+        } else {
+            let (pushed, add_y) = stack.pop().unwrap();
+            let delta: i64 = rng.gen_range(-8..=8);
+            input += &block(26, delta - add_y, rng.gen_range(1..=16));
+
+            // digit = pushed + delta
+            largest[pushed] = 9 - delta.max(0);
+            largest[digit] = 9 + delta.min(0);
+            smallest[pushed] = 1 - delta.min(0);
+            smallest[digit] = 1 + delta.max(0);
+        }
+    }
+
+    let number = |digits: &[i64]| digits.iter().map(|d| d.to_string()).collect::<String>();
+
+    Generated::new(input, number(&largest), number(&smallest))
+}
@@ -0,0 +1,50 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::Generated;
+
+// Moves every sea cucumber of a herd that can, returning whether any did
+fn move_herd(trench: &mut [Vec<u8>], herd: u8, (dr, dc): (usize, usize)) -> bool {
+    let height = trench.len();
+    let width = trench[0].len();
+
+    let moving: Vec<(usize, usize, usize, usize)> = (0..height)
+        .flat_map(|r| (0..width).map(move |c| (r, c)))
+        .filter(|&(r, c)| trench[r][c] == herd)
+        .map(|(r, c)| (r, c, (r + dr) % height, (c + dc) % width))
+        .filter(|&(_, _, r, c)| trench[r][c] == b'.')
+        .collect();
+
+    for &(r, c, nr, nc) in &moving {
+        trench[r][c] = b'.';
+        trench[nr][nc] = herd;
+    }
+
+    !moving.is_empty()
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut trench: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.gen_range(0..10) {
+                    0..=2 => b'>',
+                    3..=5 => b'v',
+                    _ => b'.',
+                })
+                .collect()
+        })
+        .collect();
+
+    let input: String = trench
+        .iter()
+        .map(|row| String::from_utf8_lossy(row) + "\n")
+        .collect();
+
+    let mut steps = 1;
+    while move_herd(&mut trench, b'>', (0, 1)) | move_herd(&mut trench, b'v', (1, 0)) {
+        steps += 1;
+    }
+
+    Generated::new(input, steps, "Merry Christmas!")
+}
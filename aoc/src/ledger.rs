@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+// Confirmed answers computed from a single input
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct Entry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl Entry {
+    fn part(&self, part: usize) -> Option<&String> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn part_mut(&mut self, part: usize) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            2 => &mut self.part2,
+            _ => panic!("Invalid part {}", part),
+        }
+    }
+}
+
+// Outcome of checking an answer against the ledger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    New,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail { .. } => write!(f, "FAIL"),
+            Self::New => write!(f, "NEW"),
+        }
+    }
+}
+
+// Known-good answers, keyed by day and then by the hash of the input they belong to
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    days: BTreeMap<String, BTreeMap<String, Entry>>,
+}
+
+impl Ledger {
+    // A missing ledger file is just an empty ledger
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let days = match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))?
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(format!("{}: {}", path.display(), e).into()),
+        };
+
+        Ok(Ledger {
+            path: path.to_path_buf(),
+            days,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn entry(&self, day: usize, input_hash: &str) -> Option<&Entry> {
+        self.days.get(&day_key(day))?.get(input_hash)
+    }
+
+    pub fn check(&self, day: usize, input_hash: &str, part: usize, answer: &str) -> Status {
+        match self.entry(day, input_hash).and_then(|e| e.part(part)) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+            },
+            None => Status::New,
+        }
+    }
+
+    pub fn record(&mut self, day: usize, input_hash: &str, part: usize, answer: &str) {
+        let entry = self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input_hash.to_string())
+            .or_default();
+
+        *entry.part_mut(part) = Some(answer.to_string());
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        let content = toml::to_string(&self.days)?;
+        fs::write(&self.path, content).map_err(|e| format!("{}: {}", self.path.display(), e))?;
+
+        Ok(())
+    }
+}
+
+fn day_key(day: usize) -> String {
+    format!("day{:02}", day)
+}
+
+// Answers are tied to the exact input they were computed from
+pub fn hash_input(input: &[u8]) -> String {
+    Sha256::digest(input)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_and_record() {
+        let mut ledger = Ledger {
+            path: PathBuf::from("answers.toml"),
+            days: BTreeMap::new(),
+        };
+        let hash = hash_input(b"input");
+
+        assert_eq!(ledger.check(1, &hash, 1, "42"), Status::New);
+
+        ledger.record(1, &hash, 1, "42");
+
+        assert_eq!(ledger.check(1, &hash, 1, "42"), Status::Pass);
+        assert_eq!(
+            ledger.check(1, &hash, 1, "43"),
+            Status::Fail {
+                expected: "42".to_string()
+            }
+        );
+        assert_eq!(ledger.check(1, &hash, 2, "42"), Status::New);
+        assert_eq!(ledger.check(1, &hash_input(b"other"), 1, "42"), Status::New);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut days: BTreeMap<String, BTreeMap<String, Entry>> = BTreeMap::new();
+        days.entry(day_key(13)).or_default().insert(
+            "abc".to_string(),
+            Entry {
+                part1: Some("729".to_string()),
+                part2: Some("#..#\n####".to_string()),
+            },
+        );
+
+        let content = toml::to_string(&days).unwrap();
+        let parsed: BTreeMap<String, BTreeMap<String, Entry>> = toml::from_str(&content).unwrap();
+
+        assert_eq!(parsed["day13"]["abc"].part2.as_deref(), Some("#..#\n####"));
+    }
+}
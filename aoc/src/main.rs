@@ -0,0 +1,427 @@
+mod days;
+mod generate;
+mod ledger;
+
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use clap::{Parser, Subcommand};
+use common::input;
+use common::report::{self, Format, Parts, Report, ReportArgs};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use days::SOLVERS;
+use generate::{Generated, GENERATORS};
+use ledger::{Ledger, Status};
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(usize),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        match s.parse::<usize>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(Self::Day(day)),
+            _ => Err(format!("Invalid day '{}', expected 1-25 or 'all'", s)),
+        }
+    }
+}
+
+impl DaySelection {
+    fn days(&self) -> Vec<usize> {
+        match self {
+            Self::All => (1..=25).collect(),
+            Self::Day(day) => vec![*day],
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Runs the Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solver of a single day, or of the whole calendar
+    Run {
+        /// Day to run (1-25), or `all`
+        day: DaySelection,
+
+        /// Only compute this part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this path, or `-` for stdin (single day only)
+        #[arg(long, short)]
+        input: Option<PathBuf>,
+
+        #[command(flatten)]
+        report: ReportArgs,
+
+        /// Ledger of confirmed answers every run is checked against
+        #[arg(long, default_value = "answers.toml")]
+        ledger: PathBuf,
+
+        /// Confirm the computed answers by writing them to the ledger
+        #[arg(long)]
+        record: bool,
+    },
+    /// Generate a random puzzle input, printing its answers when they are known
+    Generate {
+        /// Day to generate an input for (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Seed of the random generator, the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How large the input is, what it measures depends on the day
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Write the input to this path instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        /// Run the solver on the input and compare its answers with the known ones
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+// Outcome of running a single day
+struct DayReport {
+    day: usize,
+    input_hash: Option<String>,
+    report: Result<Report, Box<dyn Error>>,
+}
+
+impl DayReport {
+    // Computed answers along with the part they belong to
+    fn answers(&self) -> Vec<(usize, &str)> {
+        let Ok(report) = &self.report else {
+            return Vec::new();
+        };
+
+        [&report.part1, &report.part2]
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, part)| part.as_ref().map(|p| (i + 1, p.answer.as_str())))
+            .collect()
+    }
+
+    // Checks every computed answer against the ledger, indexed by part
+    fn check(&self, ledger: &Ledger) -> [Option<Status>; 2] {
+        let mut statuses = [None, None];
+
+        if let Some(hash) = &self.input_hash {
+            for (part, answer) in self.answers() {
+                statuses[part - 1] = Some(ledger.check(self.day, hash, part, answer));
+            }
+        }
+
+        statuses
+    }
+}
+
+// Reads the whole puzzle input up front so reading it isn't timed
+fn read_input(day: usize, path: Option<&Path>) -> io::Result<Vec<u8>> {
+    let mut reader = match path {
+        Some(path) => input::open(path)?,
+        None => input::open(&input::default_path(day))?,
+    };
+
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input)?;
+
+    Ok(input)
+}
+
+fn run_day(day: usize, path: Option<&Path>, parts: Parts, runs: usize) -> Option<DayReport> {
+    let solver = SOLVERS[day - 1]?;
+
+    let (input_hash, report) = match read_input(day, path) {
+        Ok(input) => (
+            Some(ledger::hash_input(&input)),
+            solver(day, &input, parts, runs),
+        ),
+        Err(e) => (None, Err(e.into())),
+    };
+
+    Some(DayReport {
+        day,
+        input_hash,
+        report,
+    })
+}
+
+fn print_table(reports: &[DayReport], ledger: &Ledger) {
+    println!(
+        "{:>3}  {:<20} {:<4}  {:<20} {:<4}  {:>12}",
+        "Day", "Part 1", "", "Part 2", "", "Time"
+    );
+    println!("{}", "-".repeat(72));
+
+    // Answers that span multiple lines don't fit the table, print them afterwards
+    let mut long_answers = Vec::new();
+    let mut cell = |day: usize, part: usize, answer: Option<&String>| match answer {
+        Some(a) if a.contains('\n') => {
+            long_answers.push((day, part, a.clone()));
+            "(see below)".to_string()
+        }
+        Some(a) => a.clone(),
+        None => "-".to_string(),
+    };
+
+    for day_report in reports {
+        match &day_report.report {
+            Ok(report) => {
+                let part1 = cell(report.day, 1, report.part1.as_ref().map(|p| &p.answer));
+                let part2 = cell(report.day, 2, report.part2.as_ref().map(|p| &p.answer));
+                let [status1, status2] = day_report
+                    .check(ledger)
+                    .map(|s| s.map(|s| s.to_string()).unwrap_or_default());
+                println!(
+                    "{:>3}  {:<20} {:<4}  {:<20} {:<4}  {:>11.6}s",
+                    report.day,
+                    part1,
+                    status1,
+                    part2,
+                    status2,
+                    report.total_time()
+                );
+            }
+            Err(e) => println!("{:>3}  error: {}", day_report.day, e),
+        }
+    }
+
+    for (day, part, answer) in long_answers {
+        println!("\nDay {}, part {}:\n{}", day, part, answer);
+    }
+}
+
+// Explains every answer that didn't pass, machine readable formats get this on stderr
+fn print_check_failures(reports: &[DayReport], ledger: &Ledger, to_stderr: bool) {
+    let mut lines = Vec::new();
+
+    for day_report in reports {
+        let statuses = day_report.check(ledger);
+        for (part, answer) in day_report.answers() {
+            match &statuses[part - 1] {
+                Some(Status::Fail { expected }) => lines.push(format!(
+                    "Day {}, part {}: FAIL, expected {} but got {}",
+                    day_report.day, part, expected, answer
+                )),
+                Some(Status::New) => lines.push(format!(
+                    "Day {}, part {}: NEW, no confirmed answer for this input",
+                    day_report.day, part
+                )),
+                _ => {}
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        return;
+    }
+
+    if to_stderr {
+        eprintln!("{}", lines.join("\n"));
+    } else {
+        println!("\n{}", lines.join("\n"));
+    }
+}
+
+fn record_answers(reports: &[DayReport], ledger: &mut Ledger) -> Result<(), Box<dyn Error>> {
+    for day_report in reports {
+        if let Some(hash) = &day_report.input_hash {
+            for (part, answer) in day_report.answers() {
+                ledger.record(day_report.day, hash, part, answer);
+            }
+        }
+    }
+
+    ledger.save()
+}
+
+// Machine readable formats only carry the successful reports, failures go to stderr
+fn print_rendered(reports: &[DayReport], format: Format) {
+    let mut ok_reports = Vec::new();
+    for day_report in reports {
+        match &day_report.report {
+            Ok(report) => ok_reports.push(report.clone()),
+            Err(e) => eprintln!("Day {}: error: {}", day_report.day, e),
+        }
+    }
+
+    print!("{}", report::render(&ok_reports, format));
+}
+
+// Solves a generated input, returning whether every known answer was found
+fn check_generated(day: usize, generated: &Generated) -> Result<bool, Box<dyn Error>> {
+    let solver = SOLVERS[day - 1].ok_or(format!("No solver available for day {}", day))?;
+    let report = solver(day, generated.input.as_bytes(), Parts::new(None), 1)?;
+
+    let mut passed = true;
+    for (i, part) in [&report.part1, &report.part2].into_iter().enumerate() {
+        let Some(part) = part else {
+            continue;
+        };
+
+        let status = match &generated.answers[i] {
+            Some(expected) if *expected == part.answer => "PASS".to_string(),
+            Some(expected) => {
+                passed = false;
+                format!("FAIL, expected {}", expected)
+            }
+            None => "unknown".to_string(),
+        };
+        eprintln!(
+            "Part {}: {} ({}) in {:.6}s",
+            i + 1,
+            part.answer,
+            status,
+            part.time.median
+        );
+    }
+
+    Ok(passed)
+}
+
+fn generate(
+    day: usize,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<&Path>,
+    check: bool,
+) -> Result<bool, Box<dyn Error>> {
+    let generator = &GENERATORS[day - 1];
+
+    let size = size.unwrap_or(generator.default_size);
+    if !(generator.min_size..=generator.max_size).contains(&size) {
+        return Err(format!(
+            "Invalid size {} for day {} ({}), expected {}-{}",
+            size, day, generator.size, generator.min_size, generator.max_size
+        )
+        .into());
+    }
+
+    let generated = (generator.generate)(&mut StdRng::seed_from_u64(seed), size);
+    match output {
+        Some(path) => {
+            fs::write(path, &generated.input).map_err(|e| format!("{}: {}", path.display(), e))?
+        }
+        None => io::stdout().write_all(generated.input.as_bytes())?,
+    }
+
+    if check {
+        return check_generated(day, &generated);
+    }
+
+    for (i, answer) in generated.answers.iter().enumerate() {
+        match answer {
+            Some(answer) => eprintln!("Part {}: {}", i + 1, answer),
+            None => eprintln!("Part {}: unknown", i + 1),
+        }
+    }
+
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            report,
+            ledger: ledger_path,
+            record,
+        } => {
+            if input.is_some() && matches!(day, DaySelection::All) {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            let mut ledger = match Ledger::load(&ledger_path) {
+                Ok(ledger) => ledger,
+                Err(e) => {
+                    eprintln!("Could not load the answer ledger: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let parts = Parts::new(part);
+            let reports: Vec<_> = day
+                .days()
+                .into_iter()
+                .filter_map(|d| run_day(d, input.as_deref(), parts, report.runs as usize))
+                .collect();
+
+            if reports.is_empty() {
+                eprintln!("No solver available for {:?}", day);
+                return ExitCode::FAILURE;
+            }
+
+            match report.format {
+                Format::Human => print_table(&reports, &ledger),
+                format => print_rendered(&reports, format),
+            }
+            print_check_failures(&reports, &ledger, report.format != Format::Human);
+
+            let failed_check = reports
+                .iter()
+                .flat_map(|r| r.check(&ledger))
+                .any(|s| matches!(s, Some(Status::Fail { .. })));
+
+            if record {
+                if let Err(e) = record_answers(&reports, &mut ledger) {
+                    eprintln!("Could not save the answer ledger: {}", e);
+                    return ExitCode::FAILURE;
+                }
+                eprintln!("Recorded the answers in {}", ledger.path().display());
+            }
+
+            // Recording overwrites failed checks, so they only count when not recording
+            if reports.iter().any(|r| r.report.is_err()) || (failed_check && !record) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+            check,
+        } => match generate(day as usize, seed, size, output.as_deref(), check) {
+            Ok(true) => (),
+            Ok(false) => return ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,56 @@
+use std::error::Error;
+use std::io::Read;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::input::InputArgs;
+use crate::report::{self, Parts, ReportArgs};
+use crate::Solution;
+
+// Command line arguments shared by every day binary
+#[derive(Debug, Clone, Parser)]
+pub struct DayArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// Only compute this part of the puzzle
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    #[command(flatten)]
+    pub report: ReportArgs,
+}
+
+// Solves `day` as requested by `args` and prints the report
+pub fn run<S: Solution>(day: usize, args: &DayArgs) -> Result<(), Box<dyn Error>> {
+    // Read the whole input up front so it can be parsed on every run
+    let mut input = Vec::new();
+    args.input.open(day)?.read_to_end(&mut input)?;
+
+    let report = report::measure::<S>(
+        day,
+        &input,
+        Parts::new(args.part),
+        args.report.runs as usize,
+    )?;
+    print!("{}", report::render(&[report], args.report.format));
+
+    Ok(())
+}
+
+// Exit code of a day binary, printing its error the way the `aoc` runner does
+pub fn exit(result: Result<(), Box<dyn Error>>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Entry point of a day binary without extra subcommands
+pub fn main<S: Solution>(day: usize) -> ExitCode {
+    exit(run::<S>(day, &DayArgs::parse()))
+}
@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Where something was found in the puzzle input, both line and column are 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Self {
+        Position { line, column }
+    }
+
+    // Position of `token`, which must be a slice of `line`, `index` being the 0-based index of the line
+    pub fn of(index: usize, line: &str, token: &str) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&o| o <= line.len())
+            .expect("token is not a slice of line");
+
+        Position {
+            line: index + 1,
+            column: line[..offset].chars().count() + 1,
+        }
+    }
+
+    // Start of the line with 0-based index `index`
+    pub fn line_start(index: usize) -> Self {
+        Position {
+            line: index + 1,
+            column: 1,
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+// Parses `token`, a slice of `line`, building the error from its position and text when it isn't valid
+pub fn parse_token<T, E>(
+    index: usize,
+    line: &str,
+    token: &str,
+    error: impl FnOnce(Position, String) -> E,
+) -> Result<T, E>
+where
+    T: FromStr,
+{
+    token
+        .parse()
+        .map_err(|_| error(Position::of(index, line, token), token.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_token() {
+        let line = "forward 12";
+        let token = line.split(' ').nth(1).unwrap();
+
+        assert_eq!(Position::of(4, line, token), Position::new(5, 9));
+        assert_eq!(
+            Position::of(0, line, &line[..0]).to_string(),
+            "line 1, column 1"
+        );
+    }
+
+    #[test]
+    fn parse_token_error() {
+        let line = "1,x,3";
+        let token = line.split(',').nth(1).unwrap();
+        let error = parse_token::<u32, _>(2, line, token, |p, t| (p, t)).unwrap_err();
+
+        assert_eq!(error, (Position::new(3, 3), "x".to_string()));
+    }
+}
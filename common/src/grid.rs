@@ -0,0 +1,415 @@
+use std::fmt::Display;
+use std::io;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+use thiserror::Error;
+
+use crate::error::Position;
+
+// Offsets of the 4 orthogonal neighbors: up, left, right, down
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+// Offsets of all 8 neighbors, diagonals included, in reading order
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+fn offset_within(
+    width: usize,
+    height: usize,
+    (row, col): (usize, usize),
+    (dr, dc): (isize, isize),
+) -> Option<(usize, usize)> {
+    let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+
+    (pos.0 < height && pos.1 < width).then_some(pos)
+}
+
+// Why a character map couldn't be turned into a grid
+#[derive(Debug, Error)]
+pub enum ParseGridError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: invalid cell '{token}'")]
+    InvalidCell { position: Position, token: char },
+    #[error("{position}: expected {expected} cells, found {found}")]
+    InvalidWidth {
+        position: Position,
+        expected: usize,
+        found: usize,
+    },
+}
+
+// Rectangular grid stored row by row in a single buffer, positions are (row, column)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    // Grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // Builds every cell from its position
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(f((row, col)));
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Parses a character map, `cell` returns `None` for characters that aren't valid cells
+    pub fn parse<R: BufRead>(
+        input: R,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        Self::from_lines(input.lines().enumerate(), cell)
+    }
+
+    // Same as `parse`, but from lines paired with their 0-based index in the input
+    pub fn from_lines<I>(
+        lines: I,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError>
+    where
+        I: IntoIterator<Item = (usize, io::Result<String>)>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (index, line_result) in lines {
+            let line = line_result?;
+
+            let row_start = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or(ParseGridError::InvalidCell {
+                    position: Position::new(index + 1, column + 1),
+                    token: c,
+                })?;
+                cells.push(value);
+            }
+
+            // The grid must be rectangular
+            let row_width = cells.len() - row_start;
+            match width {
+                Some(expected) if expected != row_width => {
+                    return Err(ParseGridError::InvalidWidth {
+                        position: Position::line_start(index),
+                        expected,
+                        found: row_width,
+                    })
+                }
+                Some(_) => {}
+                None => width = Some(row_width),
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        Some(&mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    // Moves `pos` by `delta`, `None` if that falls off the grid
+    pub fn offset(&self, pos: (usize, usize), delta: (isize, isize)) -> Option<(usize, usize)> {
+        offset_within(self.width, self.height, pos, delta)
+    }
+
+    // Moves `pos` by `delta`, going off one edge comes back in from the opposite one.
+    // An empty grid has no edges, so `pos` comes back unchanged
+    pub fn offset_wrapping(
+        &self,
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+    ) -> (usize, usize) {
+        if self.is_empty() {
+            return (row, col);
+        }
+
+        let wrap = |v: usize, d: isize, size: usize| {
+            let v = v as isize + d;
+
+            // Skip the division when the move stays inside the grid, which is the common case
+            if (0..size as isize).contains(&v) {
+                v as usize
+            } else {
+                v.rem_euclid(size as isize) as usize
+            }
+        };
+
+        (wrap(row, dr, self.height), wrap(col, dc, self.width))
+    }
+
+    // Moves `pos` by `delta`, stopping at the edges of the grid.
+    // An empty grid has no edges, so `pos` comes back unchanged
+    pub fn offset_clamped(
+        &self,
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+    ) -> (usize, usize) {
+        if self.is_empty() {
+            return (row, col);
+        }
+
+        let clamp = |v: usize, d: isize, size: usize| v.saturating_add_signed(d).min(size - 1);
+
+        (clamp(row, dr, self.height), clamp(col, dc, self.width))
+    }
+
+    // Orthogonal neighbors of `pos` that are inside the grid, the grid isn't borrowed while iterating
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&d| offset_within(width, height, pos, d))
+    }
+
+    // Orthogonal and diagonal neighbors of `pos` that are inside the grid
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        ADJACENT
+            .iter()
+            .filter_map(move |&d| offset_within(width, height, pos, d))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    // Every position in reading order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    // Every cell in reading order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    // Every cell along with its position, in reading order
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        // Going row by row keeps the hot loop free of divisions
+        self.cells
+            .chunks(self.width.max(1))
+            .enumerate()
+            .flat_map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(move |(col, cell)| ((row, col), cell))
+            })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Draws the grid as a character map, one line per row
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            self.contains((row, col)),
+            "position ({}, {}) out of bounds",
+            row,
+            col
+        );
+
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            self.contains((row, col)),
+            "position ({}, {}) out of bounds",
+            row,
+            col
+        );
+
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n".as_bytes(), |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let grid = digits();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(
+            grid.render(|&d| if d % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#"
+        );
+
+        let error = Grid::parse("123\n4x6\n".as_bytes(), |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: invalid cell 'x'");
+
+        let error = Grid::parse("123\n45\n".as_bytes(), |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 3 cells, found 2"
+        );
+
+        assert!(Grid::parse("".as_bytes(), |c| c.to_digit(10))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = digits();
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn addressing() {
+        let grid = digits();
+
+        assert_eq!(grid.offset((0, 2), (0, 1)), None);
+        assert_eq!(grid.offset((0, 2), (1, -1)), Some((1, 1)));
+        assert_eq!(grid.offset_wrapping((0, 2), (0, 1)), (0, 0));
+        assert_eq!(grid.offset_wrapping((0, 0), (-1, -1)), (1, 2));
+        assert_eq!(grid.offset_clamped((0, 2), (-1, 1)), (0, 2));
+        assert_eq!(grid.offset_clamped((1, 0), (5, -5)), (1, 0));
+
+        for (width, height) in [(0, 0), (3, 0), (0, 2)] {
+            let empty = Grid::new(width, height, 0);
+            assert_eq!(empty.offset_clamped((0, 0), (1, -1)), (0, 0));
+            assert_eq!(empty.offset_wrapping((0, 0), (1, -1)), (0, 0));
+        }
+    }
+}
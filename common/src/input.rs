@@ -0,0 +1,56 @@
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+// Command line arguments every day binary accepts to locate its puzzle input
+#[derive(Debug, Clone, Args)]
+pub struct InputArgs {
+    /// Path to the puzzle input, or `-` to read it from stdin [default: inputs/dayNN]
+    #[arg(long, short)]
+    pub input: Option<PathBuf>,
+}
+
+impl InputArgs {
+    // Opens the requested input, falling back to the day's file under `inputs/`
+    pub fn open(&self, day: usize) -> io::Result<Box<dyn BufRead>> {
+        match &self.input {
+            Some(path) => open(path),
+            None => open(&default_path(day)),
+        }
+    }
+}
+
+// Where a day's puzzle input lives when no path is given
+pub fn default_path(day: usize) -> PathBuf {
+    PathBuf::from(format!("inputs/day{:02}", day))
+}
+
+// Opens `path` for reading, `-` means stdin
+pub fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = File::open(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+
+    Ok(Box::new(BufReader::new(file)))
+}
+
+// Reads a day's input from the workspace `inputs/` directory, `None` if it isn't there
+pub fn workspace_input(day: usize) -> Option<Vec<u8>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(default_path(day));
+
+    fs::read(path).ok()
+}
+
+// Same as `workspace_input`, for the ignored real input tests that can't do without it
+pub fn require_workspace_input(day: usize) -> Vec<u8> {
+    workspace_input(day).unwrap_or_else(|| panic!("{} is missing", default_path(day).display()))
+}
@@ -0,0 +1,23 @@
+pub mod cli;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod report;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::io::BufRead;
+
+// Shape shared by every day's solver: parse the puzzle input once, then compute each part from it
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+    type Error: Error + 'static;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, Self::Error>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, Self::Error>;
+}
@@ -0,0 +1,247 @@
+use std::time::{Duration, Instant};
+
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+use crate::Solution;
+
+// How reports are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+    Csv,
+}
+
+// Command line arguments controlling how solvers are measured and reported
+#[derive(Debug, Clone, Args)]
+pub struct ReportArgs {
+    /// Number of times each stage is run to gather timing statistics
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: u32,
+
+    /// Output format of the report
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    pub format: Format,
+}
+
+// Which parts of a puzzle should be computed
+#[derive(Debug, Clone, Copy)]
+pub struct Parts {
+    part1: bool,
+    part2: bool,
+}
+
+impl Parts {
+    // `None` means both parts
+    pub fn new(part: Option<u8>) -> Self {
+        Parts {
+            part1: part.is_none_or(|p| p == 1),
+            part2: part.is_none_or(|p| p == 2),
+        }
+    }
+}
+
+// Timing statistics of a stage over repeated runs, in seconds
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        secs.sort_by(f64::total_cmp);
+
+        let runs = secs.len();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+        let median = if runs.is_multiple_of(2) {
+            (secs[runs / 2 - 1] + secs[runs / 2]) / 2.0
+        } else {
+            secs[runs / 2]
+        };
+
+        Stats {
+            runs,
+            min: secs[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+// Runs `f` `runs` times (at least once), keeping the last result
+pub fn time<T, E, F>(runs: usize, mut f: F) -> Result<(T, Stats), E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+
+    for _ in 0..runs.max(1) {
+        let t0 = Instant::now();
+        let value = f()?;
+        samples.push(t0.elapsed());
+
+        result = Some(value);
+    }
+
+    Ok((result.unwrap(), Stats::from_samples(&samples)))
+}
+
+// Answer of a single part and how long it took to compute
+#[derive(Debug, Clone, Serialize)]
+pub struct PartReport {
+    pub answer: String,
+    pub time: Stats,
+}
+
+// Everything measured while solving a day
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub day: usize,
+    pub parse: Stats,
+    pub part1: Option<PartReport>,
+    pub part2: Option<PartReport>,
+}
+
+impl Report {
+    // Adds up the median time of every stage that was run
+    pub fn total_time(&self) -> f64 {
+        let parts_time: f64 = [&self.part1, &self.part2]
+            .into_iter()
+            .flatten()
+            .map(|p| p.time.median)
+            .sum();
+
+        self.parse.median + parts_time
+    }
+}
+
+// Parses `input` and computes the requested parts, timing every stage over `runs` runs
+pub fn measure<S: Solution>(
+    day: usize,
+    input: &[u8],
+    parts: Parts,
+    runs: usize,
+) -> Result<Report, S::Error> {
+    let (parsed, parse) = time(runs, || S::parse(input))?;
+
+    let part1 = if parts.part1 {
+        let (answer, time) = time(runs, || S::part1(&parsed))?;
+        Some(PartReport {
+            answer: answer.to_string(),
+            time,
+        })
+    } else {
+        None
+    };
+
+    let part2 = if parts.part2 {
+        let (answer, time) = time(runs, || S::part2(&parsed))?;
+        Some(PartReport {
+            answer: answer.to_string(),
+            time,
+        })
+    } else {
+        None
+    };
+
+    Ok(Report {
+        day,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+pub fn render(reports: &[Report], format: Format) -> String {
+    match format {
+        Format::Human => reports
+            .iter()
+            .map(render_human)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Format::Json => serde_json::to_string_pretty(reports).unwrap() + "\n",
+        Format::Csv => render_csv(reports),
+    }
+}
+
+fn human_time(stats: &Stats) -> String {
+    if stats.runs == 1 {
+        return format!("{:.9}s", stats.median);
+    }
+
+    format!(
+        "{:.9}s median over {} runs (min {:.9}s, mean {:.9}s, stddev {:.9}s)",
+        stats.median, stats.runs, stats.min, stats.mean, stats.stddev
+    )
+}
+
+fn render_human(report: &Report) -> String {
+    let mut out = format!(
+        "Day {}\nParsing the input took {}\n",
+        report.day,
+        human_time(&report.parse)
+    );
+
+    for (i, part) in [&report.part1, &report.part2].into_iter().enumerate() {
+        if let Some(part) = part {
+            out += &format!(
+                "\nPart {}:\nTook {}\n{}\n",
+                i + 1,
+                human_time(&part.time),
+                part.answer
+            );
+        }
+    }
+
+    out
+}
+
+// Quotes a CSV field if it contains anything that would break the row
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv_row(day: usize, stage: &str, answer: &str, stats: &Stats) -> String {
+    format!(
+        "{},{},{},{},{:.9},{:.9},{:.9},{:.9}\n",
+        day,
+        stage,
+        csv_field(answer),
+        stats.runs,
+        stats.min,
+        stats.median,
+        stats.mean,
+        stats.stddev
+    )
+}
+
+fn render_csv(reports: &[Report]) -> String {
+    let mut out = String::from("day,stage,answer,runs,min,median,mean,stddev\n");
+
+    for report in reports {
+        out += &csv_row(report.day, "parse", "", &report.parse);
+
+        let parts = [("part1", &report.part1), ("part2", &report.part2)];
+        for (stage, part) in parts {
+            if let Some(part) = part {
+                out += &csv_row(report.day, stage, &part.answer, &part.time);
+            }
+        }
+    }
+
+    out
+}
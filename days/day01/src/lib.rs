@@ -0,0 +1,122 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day01Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: invalid depth '{token}'")]
+    InvalidDepth { position: Position, token: String },
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Output1 = u32;
+    type Output2 = u32;
+    type Error = Day01Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Vec<u32>, Day01Error> {
+        let mut depths = Vec::new();
+
+        // Read line by line
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            // Parse string to u32
+            let depth = error::parse_token(index, &line, &line, |position, token| {
+                Day01Error::InvalidDepth { position, token }
+            })?;
+
+            depths.push(depth);
+        }
+
+        Ok(depths)
+    }
+
+    fn part1(depths: &Vec<u32>) -> Result<u32, Day01Error> {
+        let mut n_increases = 0;
+        for i in 1..depths.len() {
+            if depths[i] > depths[i - 1] {
+                n_increases += 1
+            }
+        }
+
+        Ok(n_increases)
+    }
+
+    fn part2(depths: &Vec<u32>) -> Result<u32, Day01Error> {
+        let mut n_increases = 0;
+        let window_size = 3;
+
+        let mut window_iter = depths.windows(window_size);
+        let Some(mut prev_window) = window_iter.next() else {
+            return Ok(0);
+        };
+        for window in window_iter {
+            let prev_sum: u32 = prev_window.iter().sum();
+            let next_sum: u32 = window.iter().sum();
+
+            if next_sum > prev_sum {
+                n_increases += 1;
+            }
+
+            prev_window = window;
+        }
+
+        Ok(n_increases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day01::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day01::part1(&input).unwrap(), 7);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day01::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day01::part2(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day01::parse("199\n2x0\n".as_bytes()).unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2, column 1: invalid depth '2x0'");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(1);
+        let input = Day01::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day01::part1(&input).unwrap(), 1233);
+        assert_eq!(Day01::part2(&input).unwrap(), 1275);
+    }
+}
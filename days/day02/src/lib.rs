@@ -0,0 +1,186 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day02Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: expected '<direction> <offset>', got '{line}'")]
+    InvalidFormat { position: Position, line: String },
+    #[error("{position}: invalid direction '{token}', expected forward, up or down")]
+    InvalidDirection { position: Position, token: String },
+    #[error("{position}: invalid offset '{token}'")]
+    InvalidOffset { position: Position, token: String },
+}
+
+#[derive(Debug)]
+struct Submarine {
+    aim: i64,
+    depth: u64,
+    horizontal_position: u64,
+}
+
+#[derive(Debug)]
+pub enum SubMovement {
+    Forward(u64),
+    Up(u64),
+    Down(u64),
+}
+
+impl Submarine {
+    fn new() -> Self {
+        Submarine {
+            aim: 0,
+            depth: 0,
+            horizontal_position: 0,
+        }
+    }
+
+    fn maneuver_part1(&mut self, movement: &SubMovement) {
+        match movement {
+            SubMovement::Forward(d) => self.horizontal_position += d,
+            SubMovement::Up(d) => self.depth = self.depth.saturating_sub(*d),
+            SubMovement::Down(d) => self.depth += d,
+        }
+    }
+
+    fn maneuver_part2(&mut self, movement: &SubMovement) {
+        match movement {
+            SubMovement::Forward(d) => {
+                self.horizontal_position += d;
+                self.depth = if self.aim.is_positive() {
+                    self.depth + *d * (self.aim as u64)
+                } else {
+                    self.depth.saturating_sub(*d * (self.aim as u64))
+                }
+            }
+            SubMovement::Up(d) => self.aim -= *d as i64,
+            SubMovement::Down(d) => self.aim += *d as i64,
+        }
+    }
+
+    fn multiplied_position(&self) -> u64 {
+        self.depth * self.horizontal_position
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<SubMovement>;
+    type Output1 = u64;
+    type Output2 = u64;
+    type Error = Day02Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Vec<SubMovement>, Day02Error> {
+        let mut movements = Vec::new();
+
+        // Read line by line
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            // Split line into its fields
+            let fields: Vec<&str> = line.split(' ').collect();
+
+            if fields.len() != 2 {
+                return Err(Day02Error::InvalidFormat {
+                    position: Position::line_start(index),
+                    line,
+                });
+            }
+
+            // Parse the offset part of the movement
+            let offset = error::parse_token(index, &line, fields[1], |position, token| {
+                Day02Error::InvalidOffset { position, token }
+            })?;
+
+            // Parse the direction part of the movement
+            let movement = match fields[0] {
+                "forward" => SubMovement::Forward(offset),
+                "up" => SubMovement::Up(offset),
+                "down" => SubMovement::Down(offset),
+                f => {
+                    return Err(Day02Error::InvalidDirection {
+                        position: Position::of(index, &line, f),
+                        token: f.to_string(),
+                    })
+                }
+            };
+
+            movements.push(movement);
+        }
+
+        Ok(movements)
+    }
+
+    fn part1(movements: &Vec<SubMovement>) -> Result<u64, Day02Error> {
+        let mut submarine = Submarine::new();
+        for movement in movements {
+            submarine.maneuver_part1(movement);
+        }
+
+        Ok(submarine.multiplied_position())
+    }
+
+    fn part2(movements: &Vec<SubMovement>) -> Result<u64, Day02Error> {
+        let mut submarine = Submarine::new();
+        for movement in movements {
+            submarine.maneuver_part2(movement);
+        }
+
+        Ok(submarine.multiplied_position())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day02::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day02::part1(&input).unwrap(), 150);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day02::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day02::part2(&input).unwrap(), 900);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day02::parse("forward 5\nback 3\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: invalid direction 'back', expected forward, up or down"
+        );
+
+        let error = Day02::parse("up -3\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 4: invalid offset '-3'");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(2);
+        let input = Day02::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day02::part1(&input).unwrap(), 1936494);
+        assert_eq!(Day02::part2(&input).unwrap(), 1997106066);
+    }
+}
@@ -0,0 +1,338 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::Position;
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day03Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: invalid bit '{token}'")]
+    InvalidBit { position: Position, token: char },
+    #[error("{position}: expected {expected} bits, found {found}")]
+    InvalidWidth {
+        position: Position,
+        expected: usize,
+        found: usize,
+    },
+    #[error("{position}: empty line")]
+    EmptyLine { position: Position },
+    #[error("{position}: {found} bits don't fit in {max}")]
+    TooWide {
+        position: Position,
+        found: usize,
+        max: u32,
+    },
+    #[error("the {rating} filter leaves {left} numbers instead of one")]
+    NoSingleRating { rating: &'static str, left: usize },
+    #[error("'{bits}' isn't a binary number of at most 32 bits")]
+    InvalidNumber { bits: String },
+    #[error("the diagnostic report is empty")]
+    Empty,
+}
+
+fn count_one_bits_by_column(binary_numbers: &[String]) -> Vec<usize> {
+    let n_columns = binary_numbers.first().map_or(0, String::len);
+
+    let n_one_bits = binary_numbers
+        .iter()
+        .fold(vec![0; n_columns], |mut acc, number_str| {
+            for (i, digit) in number_str.chars().enumerate() {
+                if digit == '1' {
+                    acc[i] += 1;
+                }
+            }
+
+            acc
+        });
+
+    n_one_bits
+}
+
+fn parse_binary(bits: &str) -> Result<u32, Day03Error> {
+    u32::from_str_radix(bits, 2).map_err(|_| Day03Error::InvalidNumber {
+        bits: bits.to_string(),
+    })
+}
+
+fn compute_gamma_rate(binary_numbers: &[String]) -> Result<u32, Day03Error> {
+    // Get 1's count by column
+    let n_one_bits = count_one_bits_by_column(binary_numbers);
+
+    // Map vector of 1's counts into a binary string
+    let n_lines = binary_numbers.len();
+    let gamma_str: String = n_one_bits
+        .iter()
+        .map(|c| if c * 2 < n_lines { '0' } else { '1' })
+        .collect();
+
+    // Convert binary string to u32
+    parse_binary(&gamma_str)
+}
+
+fn compute_epsilon_rate(binary_numbers: &[String]) -> Result<u32, Day03Error> {
+    // Get 1's count by column
+    let n_one_bits = count_one_bits_by_column(binary_numbers);
+
+    // Map vector of 1's counts into a binary string
+    let n_lines = binary_numbers.len();
+    let epsilon_str: String = n_one_bits
+        .iter()
+        .map(|c| if c * 2 < n_lines { '1' } else { '0' })
+        .collect();
+
+    // Convert binary string to u32
+    parse_binary(&epsilon_str)
+}
+
+fn compute_oxygen_generator_rating(mut binary_numbers: Vec<String>) -> Result<u32, Day03Error> {
+    let no_single_rating = |left| Day03Error::NoSingleRating {
+        rating: "oxygen generator rating",
+        left,
+    };
+
+    // Get 1's count by column
+    let mut n_one_bits = count_one_bits_by_column(&binary_numbers);
+
+    let mut n_lines = binary_numbers.len();
+    let mut column_index = 0;
+    while binary_numbers.len() > 1 {
+        // Duplicated numbers are still all there after the last column
+        if column_index == n_one_bits.len() {
+            return Err(no_single_rating(binary_numbers.len()));
+        }
+
+        // Check which is the most common bit on column `column_index`
+        let most_common_bit = if n_one_bits[column_index] * 2 >= n_lines {
+            '1'
+        } else {
+            '0'
+        };
+
+        // Keep only numbers with `most_common_bit` on position `column_index`
+        binary_numbers.retain(|n| n[column_index..].starts_with(most_common_bit));
+
+        // Update number of lines
+        n_lines = binary_numbers.len();
+
+        // Update 1's count
+        n_one_bits = count_one_bits_by_column(&binary_numbers);
+
+        column_index += 1;
+    }
+
+    // No number is left when they all share the bit filtered out
+    match &binary_numbers[..] {
+        [number] => parse_binary(number),
+        _ => Err(no_single_rating(binary_numbers.len())),
+    }
+}
+
+fn compute_co2_scrubber_rating(mut binary_numbers: Vec<String>) -> Result<u32, Day03Error> {
+    let no_single_rating = |left| Day03Error::NoSingleRating {
+        rating: "CO2 scrubber rating",
+        left,
+    };
+
+    // Get 1's count by column
+    let mut n_one_bits = count_one_bits_by_column(&binary_numbers);
+
+    let mut n_lines = binary_numbers.len();
+    let mut column_index = 0;
+    while binary_numbers.len() > 1 {
+        // Duplicated numbers are still all there after the last column
+        if column_index == n_one_bits.len() {
+            return Err(no_single_rating(binary_numbers.len()));
+        }
+
+        // Check which is the least common bit on column `column_index`
+        let least_common_bit = if n_one_bits[column_index] * 2 >= n_lines {
+            '0'
+        } else {
+            '1'
+        };
+
+        // Keep only numbers with `most_common_bit` on position `column_index`
+        binary_numbers.retain(|n| n[column_index..].starts_with(least_common_bit));
+
+        // Update number of lines
+        n_lines = binary_numbers.len();
+
+        // Update 1's count
+        n_one_bits = count_one_bits_by_column(&binary_numbers);
+
+        column_index += 1;
+    }
+
+    // No number is left when they all share the bit filtered out
+    match &binary_numbers[..] {
+        [number] => parse_binary(number),
+        _ => Err(no_single_rating(binary_numbers.len())),
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Output1 = u64;
+    type Output2 = u64;
+    type Error = Day03Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Vec<String>, Day03Error> {
+        let mut binary_numbers: Vec<String> = Vec::new();
+
+        // Read line by line, every number must have the same width
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            if let Some((column, bit)) = line.chars().enumerate().find(|(_, c)| !"01".contains(*c))
+            {
+                return Err(Day03Error::InvalidBit {
+                    position: Position::new(index + 1, column + 1),
+                    token: bit,
+                });
+            }
+
+            if line.is_empty() {
+                return Err(Day03Error::EmptyLine {
+                    position: Position::line_start(index),
+                });
+            }
+            if line.len() > u32::BITS as usize {
+                return Err(Day03Error::TooWide {
+                    position: Position::line_start(index),
+                    found: line.len(),
+                    max: u32::BITS,
+                });
+            }
+
+            if let Some(first) = binary_numbers.first() {
+                if line.len() != first.len() {
+                    return Err(Day03Error::InvalidWidth {
+                        position: Position::line_start(index),
+                        expected: first.len(),
+                        found: line.len(),
+                    });
+                }
+            }
+
+            binary_numbers.push(line);
+        }
+
+        if binary_numbers.is_empty() {
+            return Err(Day03Error::Empty);
+        }
+
+        Ok(binary_numbers)
+    }
+
+    fn part1(binary_numbers: &Vec<String>) -> Result<u64, Day03Error> {
+        let gamma = compute_gamma_rate(binary_numbers)?;
+        let epsilon = compute_epsilon_rate(binary_numbers)?;
+
+        // Both rates fit in 32 bits, so their product always fits in 64
+        Ok(u64::from(gamma) * u64::from(epsilon))
+    }
+
+    fn part2(binary_numbers: &Vec<String>) -> Result<u64, Day03Error> {
+        let oxygen_generator_rating = compute_oxygen_generator_rating(binary_numbers.clone())?;
+        let co2_scrubber_rating = compute_co2_scrubber_rating(binary_numbers.clone())?;
+
+        Ok(u64::from(oxygen_generator_rating) * u64::from(co2_scrubber_rating))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day03::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day03::part1(&input).unwrap(), 198);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day03::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day03::part2(&input).unwrap(), 230);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day03::parse("00100\n11120\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 4: invalid bit '2'");
+
+        let error = Day03::parse("00100\n1110\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 5 bits, found 4"
+        );
+
+        let error = Day03::parse("\n\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: empty line");
+
+        let error = Day03::parse("00100\n\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: empty line");
+
+        let wide = "1".repeat(33);
+        let error = Day03::parse(wide.as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: 33 bits don't fit in 32"
+        );
+
+        let widest = format!("{}{}\n", "1".repeat(16), "0".repeat(16));
+        let input = Day03::parse(widest.as_bytes()).unwrap();
+        assert_eq!(Day03::part1(&input).unwrap(), 0xFFFF0000 * 0xFFFF);
+        assert_eq!(Day03::part2(&input).unwrap(), 0xFFFF0000 * 0xFFFF0000);
+
+        assert!(matches!(
+            Day03::parse("".as_bytes()),
+            Err(Day03Error::Empty)
+        ));
+
+        let input = Day03::parse("101\n101\n".as_bytes()).unwrap();
+        let error = Day03::part2(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the oxygen generator rating filter leaves 2 numbers instead of one"
+        );
+
+        let input = Day03::parse("11\n10\n".as_bytes()).unwrap();
+        let error = Day03::part2(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the CO2 scrubber rating filter leaves 0 numbers instead of one"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(3);
+        let input = Day03::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day03::part1(&input).unwrap(), 4160394);
+        assert_eq!(Day03::part2(&input).unwrap(), 4125600);
+    }
+}
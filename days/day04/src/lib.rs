@@ -0,0 +1,336 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day04Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("the input is empty")]
+    Empty,
+    #[error("{position}: invalid number '{token}'")]
+    InvalidNumber { position: Position, token: String },
+    #[error("{position}: board row found before the blank line that starts a board")]
+    UnexpectedRow { position: Position },
+    #[error("no board wins with the drawn numbers")]
+    NoWinner,
+}
+
+#[derive(Clone)]
+pub struct BoardSquare {
+    number: u8,
+    marked: bool,
+}
+
+impl BoardSquare {
+    fn new(number: u8) -> Self {
+        BoardSquare {
+            number,
+            marked: false,
+        }
+    }
+
+    fn mark(&mut self) {
+        self.marked = true;
+    }
+}
+
+#[derive(Clone)]
+pub struct BingoBoard {
+    id: usize,
+    numbers: Vec<Vec<BoardSquare>>,
+    has_won: bool,
+}
+
+impl BingoBoard {
+    fn new(id: usize, raw_board: Vec<Vec<u8>>) -> Self {
+        let mut board = Vec::new();
+        for (i, row) in raw_board.into_iter().enumerate() {
+            board.push(Vec::new());
+
+            for number in row {
+                board[i].push(BoardSquare::new(number))
+            }
+        }
+
+        BingoBoard {
+            id,
+            numbers: board,
+            has_won: false,
+        }
+    }
+
+    fn iter_nrow(&self, row_index: usize) -> impl Iterator<Item = &BoardSquare> {
+        self.numbers[row_index].iter()
+    }
+
+    fn iter_ncolumn(&self, column_index: usize) -> impl Iterator<Item = &BoardSquare> {
+        self.numbers.iter().map(move |r| &r[column_index])
+    }
+
+    fn mark_ball(&mut self, ball: u8) -> Option<(usize, usize)> {
+        let mut marked_position = None;
+        for (i, row) in self.numbers.iter().enumerate() {
+            let drawn_column = row.iter().position(|n| n.number == ball);
+
+            if let Some(column) = drawn_column {
+                self.numbers[i][column].mark();
+                marked_position = Some((i, column));
+
+                break;
+            }
+        }
+
+        marked_position
+    }
+
+    fn check_win_condition(&mut self, row: usize, column: usize) -> bool {
+        // Check the row first
+        let row_won = self.iter_nrow(row).all(|n| n.marked);
+
+        if row_won {
+            self.has_won = true;
+        }
+
+        // Row didn't win, check the column
+        let column_won = self.iter_ncolumn(column).all(|n| n.marked);
+
+        if column_won {
+            self.has_won = true;
+        }
+
+        self.has_won
+    }
+}
+
+pub struct BingoCaller {
+    draw_sequence: Vec<u8>,
+    boards: Vec<BingoBoard>,
+}
+
+impl BingoCaller {
+    fn new(draw_sequence: Vec<u8>, boards: Vec<BingoBoard>) -> Self {
+        BingoCaller {
+            draw_sequence,
+            boards,
+        }
+    }
+
+    // `None` once every number has been drawn
+    fn draw(&mut self) -> Option<(u8, usize, Option<usize>)> {
+        if self.draw_sequence.is_empty() {
+            return None;
+        }
+        let ball = self.draw_sequence.remove(0);
+
+        let mut last_winner_id = None;
+        let mut n_winners = 0;
+        for board in self.boards.iter_mut() {
+            if board.has_won {
+                continue;
+            }
+
+            let position_option = board.mark_ball(ball);
+            if let Some(position) = position_option {
+                if board.check_win_condition(position.0, position.1) {
+                    last_winner_id = Some(board.id);
+                    n_winners += 1;
+                }
+            }
+        }
+
+        Some((ball, n_winners, last_winner_id))
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = (Vec<u8>, Vec<Vec<Vec<u8>>>);
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day04Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day04Error> {
+        let mut boards = Vec::new();
+
+        let invalid_number = |position, token| Day04Error::InvalidNumber { position, token };
+
+        // Setup lines iterator
+        let mut lines_iter = input.lines().enumerate();
+
+        // The first line is the draw sequence
+        let (_, first_line) = lines_iter.next().ok_or(Day04Error::Empty)?;
+        let first_line = first_line?;
+        let draw_sequence = first_line
+            .split(',')
+            .map(|a| error::parse_token(0, &first_line, a, invalid_number))
+            .collect::<Result<_, _>>()?;
+
+        // Parse the remaining lines
+        for (index, line_result) in lines_iter {
+            let line = line_result?;
+
+            // If it's an empty line, it means we're going to start reading a new board
+            if line.is_empty() {
+                boards.push(Vec::new());
+                continue;
+            }
+
+            // Get mutable reference to current board
+            let current_board = boards.last_mut().ok_or(Day04Error::UnexpectedRow {
+                position: Position::line_start(index),
+            })?;
+
+            // Build and push new row
+            let new_row = line
+                .split_ascii_whitespace()
+                .map(|a| error::parse_token(index, &line, a, invalid_number))
+                .collect::<Result<_, _>>()?;
+            current_board.push(new_row);
+        }
+
+        Ok((draw_sequence, boards))
+    }
+
+    fn part1((draw_sequence, raw_boards): &Self::Input) -> Result<usize, Day04Error> {
+        // Build our objects
+        let mut boards = Vec::new();
+        for (i, raw_board) in raw_boards.iter().enumerate() {
+            boards.push(BingoBoard::new(i, raw_board.to_vec()));
+        }
+
+        let mut bingo_caller = BingoCaller::new(draw_sequence.to_vec(), boards);
+
+        // Run the game until a board wins
+        let last_ball: u8;
+        let winner_board_id: usize;
+        loop {
+            let draw = bingo_caller.draw().ok_or(Day04Error::NoWinner)?;
+            if let (drawn_ball, _, Some(winner)) = draw {
+                // Found our winner!
+                last_ball = drawn_ball;
+                winner_board_id = winner;
+                break;
+            }
+        }
+
+        // Sum the unmarked squares
+        let unmarked_sum = bingo_caller.boards[winner_board_id]
+            .numbers
+            .iter()
+            .flatten()
+            .fold(0, |acc, s| {
+                acc + if s.marked { 0 } else { s.number as usize }
+            });
+
+        // Calculate final score
+        let final_score = unmarked_sum * last_ball as usize;
+
+        Ok(final_score)
+    }
+
+    fn part2((draw_sequence, raw_boards): &Self::Input) -> Result<usize, Day04Error> {
+        // Build our objects
+        let mut boards = Vec::new();
+        for (i, raw_board) in raw_boards.iter().enumerate() {
+            boards.push(BingoBoard::new(i, raw_board.to_vec()));
+        }
+
+        let mut bingo_caller = BingoCaller::new(draw_sequence.to_vec(), boards);
+
+        // Run the game until all the boards win
+        let nboards = raw_boards.len();
+        let mut nwins = 0;
+        let mut last_ball = 0;
+        let mut last_winner_board_id = 0;
+        while nwins < nboards {
+            let draw = bingo_caller.draw().ok_or(Day04Error::NoWinner)?;
+            if let (drawn_ball, nwinners, Some(winner)) = draw {
+                // Found our winner!
+                last_ball = drawn_ball;
+                last_winner_board_id = winner;
+                nwins += nwinners;
+            }
+        }
+
+        // Sum the unmarked squares
+        let unmarked_sum = bingo_caller.boards[last_winner_board_id]
+            .numbers
+            .iter()
+            .flatten()
+            .fold(0, |acc, s| {
+                acc + if s.marked { 0 } else { s.number as usize }
+            });
+
+        // Calculate final score
+        let final_score = unmarked_sum * last_ball as usize;
+
+        Ok(final_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day04::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day04::part1(&input).unwrap(), 4512);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day04::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day04::part2(&input).unwrap(), 1924);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day04::parse("7,4,x9\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 5: invalid number 'x9'");
+
+        let error = Day04::parse("7,4\n\n 1  2\n 3 -4\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 4: invalid number '-4'");
+
+        let input = Day04::parse("7,4\n\n1 2\n3 4\n".as_bytes()).unwrap();
+        assert!(matches!(Day04::part1(&input), Err(Day04Error::NoWinner)));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(4);
+        let input = Day04::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day04::part1(&input).unwrap(), 46920);
+        assert_eq!(Day04::part2(&input).unwrap(), 12635);
+    }
+}
@@ -0,0 +1,276 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::grid::Grid;
+use common::Solution;
+use regex::Regex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day05Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: expected 'x1,y1 -> x2,y2', got '{line}'")]
+    InvalidLine { position: Position, line: String },
+    #[error("{position}: invalid coordinate '{token}'")]
+    InvalidCoordinate { position: Position, token: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl Point {
+    fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    point_a: Point,
+    point_b: Point,
+}
+
+impl Line {
+    fn new(point_a: Point, point_b: Point) -> Self {
+        Line { point_a, point_b }
+    }
+}
+
+struct Diagram {
+    min: Point,
+    values: Grid<usize>,
+}
+
+impl Diagram {
+    fn new(min: Point, max: Point) -> Self {
+        let values = Grid::new(max.x - min.x + 1, max.y - min.y + 1, 0);
+
+        Diagram { min, values }
+    }
+
+    fn fill(mut self, lines: &[Line]) -> Self {
+        for line in lines {
+            if line.point_a.x == line.point_b.x {
+                // Horizontal line
+                let x = line.point_a.x - self.min.x;
+                let (y1, y2) = if line.point_a.y > line.point_b.y {
+                    (line.point_b.y - self.min.y, line.point_a.y - self.min.y)
+                } else {
+                    (line.point_a.y - self.min.y, line.point_b.y - self.min.y)
+                };
+
+                for y in y1..=y2 {
+                    self.values[(y, x)] += 1;
+                }
+            } else if line.point_a.y == line.point_b.y {
+                // Vertical line
+                let y = line.point_a.y - self.min.y;
+                let (x1, x2) = if line.point_a.x > line.point_b.x {
+                    (line.point_b.x - self.min.x, line.point_a.x - self.min.x)
+                } else {
+                    (line.point_a.x - self.min.x, line.point_b.x - self.min.x)
+                };
+
+                for x in x1..=x2 {
+                    self.values[(y, x)] += 1;
+                }
+            } else {
+                // Diagonal line (always 45 degrees)
+                let (x1, y1) = (line.point_a.x - self.min.x, line.point_a.y - self.min.y);
+                let (x2, y2) = (line.point_b.x - self.min.x, line.point_b.y - self.min.y);
+                let line_iter: Vec<(usize, usize)> = if x1 > x2 {
+                    if y1 > y2 {
+                        (((y2..=y1).rev()).zip((x2..=x1).rev())).collect()
+                    } else {
+                        ((y1..=y2).zip((x2..=x1).rev())).collect()
+                    }
+                } else if y1 > y2 {
+                    (((y2..=y1).rev()).zip(x1..=x2)).collect()
+                } else {
+                    ((y1..=y2).zip(x1..=x2)).collect()
+                };
+
+                for (y, x) in line_iter {
+                    self.values[(y, x)] += 1;
+                }
+            }
+        }
+
+        self
+    }
+
+    fn overlap_count(&self) -> usize {
+        self.values.iter().filter(|&&v| v > 1).count()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Line>, Point, Point);
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day05Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day05Error> {
+        // Regular expression used to match against the input lines
+        let re = Regex::new(r"^(\d+),(\d+)\s*->\s*(\d+),(\d+)$").unwrap();
+
+        let mut lines = Vec::new();
+
+        let mut min_x = usize::MAX;
+        let mut max_x = 0;
+        let mut min_y = usize::MAX;
+        let mut max_y = 0;
+
+        // Read line by line
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            // Get captures
+            let caps = match re.captures(&line) {
+                Some(caps) => caps,
+                None => {
+                    return Err(Day05Error::InvalidLine {
+                        position: Position::line_start(index),
+                        line,
+                    })
+                }
+            };
+
+            // Extract points from captures, the regex guarantees all four are there
+            let mut coordinates = [0; 4];
+            for (coordinate, cap) in coordinates.iter_mut().zip(caps.iter().skip(1).flatten()) {
+                *coordinate = error::parse_token(index, &line, cap.as_str(), |position, token| {
+                    Day05Error::InvalidCoordinate { position, token }
+                })?;
+            }
+            let [x1, y1, x2, y2] = coordinates;
+
+            // Updates max and min
+            if x1 > max_x {
+                max_x = x1
+            }
+            if x2 > max_x {
+                max_x = x2
+            }
+
+            if x1 < min_x {
+                min_x = x1
+            }
+            if x2 < min_x {
+                min_x = x2
+            }
+
+            if y1 > max_y {
+                max_y = y1
+            }
+            if y2 > max_y {
+                max_y = y2
+            }
+
+            if y1 < min_y {
+                min_y = y1
+            }
+            if y2 < min_y {
+                min_y = y2
+            }
+
+            // Build line
+            let point_a = Point::new(x1, y1);
+            let point_b = Point::new(x2, y2);
+            let new_line = Line::new(point_a, point_b);
+            lines.push(new_line);
+        }
+
+        // Build extreme points
+        let min_extreme = Point::new(min_x, min_y);
+        let max_extreme = Point::new(max_x, max_y);
+
+        Ok((lines, min_extreme, max_extreme))
+    }
+
+    fn part1((lines, min_extreme, max_extreme): &Self::Input) -> Result<usize, Day05Error> {
+        // Keep only horizontal and vertical lines
+        let lines: Vec<Line> = lines
+            .iter()
+            .filter(|l| l.point_a.x == l.point_b.x || l.point_a.y == l.point_b.y)
+            .cloned()
+            .collect();
+
+        // Build and fill diagram with overlaps
+        let diagram = Diagram::new(min_extreme.clone(), max_extreme.clone()).fill(&lines);
+
+        // Return the overlap count
+        Ok(diagram.overlap_count())
+    }
+
+    fn part2((lines, min_extreme, max_extreme): &Self::Input) -> Result<usize, Day05Error> {
+        // Build and fill diagram with overlaps
+        let diagram = Diagram::new(min_extreme.clone(), max_extreme.clone()).fill(lines);
+
+        // Return the overlap count
+        Ok(diagram.overlap_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day05::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day05::part1(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day05::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day05::part2(&input).unwrap(), 12);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day05::parse("0,9 -> 5,9\n8,0 => 0,8\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 'x1,y1 -> x2,y2', got '8,0 => 0,8'"
+        );
+
+        let error = Day05::parse("0,9 -> 99999999999999999999999,9\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 8: invalid coordinate '99999999999999999999999'"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(5);
+        let input = Day05::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day05::part1(&input).unwrap(), 5373);
+        assert_eq!(Day05::part2(&input).unwrap(), 21514);
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day06Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("the input is empty")]
+    Empty,
+    #[error("{position}: invalid timer '{token}'")]
+    InvalidTimer { position: Position, token: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct LanternfishShoal(HashMap<u8, usize>);
+
+impl LanternfishShoal {
+    fn new(map: HashMap<u8, usize>) -> Self {
+        LanternfishShoal(map)
+    }
+
+    fn simulate(&mut self, days: usize) -> usize {
+        for _ in 0..days {
+            let mut next_fish_pop = HashMap::new();
+            for (cycle, count) in self.0.iter() {
+                if *cycle == 0 {
+                    *(next_fish_pop.entry(8).or_insert(0)) += count;
+                    *(next_fish_pop.entry(6).or_insert(0)) += count;
+                    continue;
+                }
+
+                *(next_fish_pop.entry(cycle - 1).or_insert(0)) += count;
+            }
+
+            self.0 = next_fish_pop;
+        }
+
+        self.0.values().sum()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = LanternfishShoal;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day06Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<LanternfishShoal, Day06Error> {
+        let mut lanternfish_map = HashMap::new();
+
+        // There's only one line in the input
+        let cycles_str = match input.lines().next() {
+            Some(line_result) => line_result?,
+            None => return Err(Day06Error::Empty),
+        };
+
+        // Parse cycles, a timer never goes above 8
+        let cycles: Vec<u8> = cycles_str
+            .split(',')
+            .map(|a| {
+                error::parse_token(0, &cycles_str, a, |position, token| {
+                    Day06Error::InvalidTimer { position, token }
+                })
+                .and_then(|cycle| match cycle {
+                    0..=8 => Ok(cycle),
+                    _ => Err(Day06Error::InvalidTimer {
+                        position: Position::of(0, &cycles_str, a),
+                        token: a.to_string(),
+                    }),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        for cycle in cycles {
+            *lanternfish_map.entry(cycle).or_insert(0) += 1;
+        }
+
+        Ok(LanternfishShoal::new(lanternfish_map))
+    }
+
+    fn part1(lanternfish_shoal: &LanternfishShoal) -> Result<usize, Day06Error> {
+        Ok(lanternfish_shoal.clone().simulate(80))
+    }
+
+    fn part2(lanternfish_shoal: &LanternfishShoal) -> Result<usize, Day06Error> {
+        Ok(lanternfish_shoal.clone().simulate(256))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3,4,3,1,2
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day06::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day06::part1(&input).unwrap(), 5934);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day06::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day06::part2(&input).unwrap(), 26984457539);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day06::parse("3,4,9,1\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 5: invalid timer '9'");
+
+        assert!(matches!(
+            Day06::parse("".as_bytes()),
+            Err(Day06Error::Empty)
+        ));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(6);
+        let input = Day06::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day06::part1(&input).unwrap(), 372300);
+        assert_eq!(Day06::part2(&input).unwrap(), 1675781200288);
+    }
+}
@@ -0,0 +1,129 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day07Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("the input is empty")]
+    Empty,
+    #[error("{position}: invalid position '{token}'")]
+    InvalidPosition { position: Position, token: String },
+}
+
+fn calc_fuel_constant(positions: &[usize], final_position: usize) -> usize {
+    positions.iter().fold(0, |acc, pos| {
+        let diff = (*pos as isize) - (final_position as isize);
+        acc + diff.unsigned_abs()
+    })
+}
+
+fn calc_fuel_variable(positions: &[usize], final_position: usize) -> usize {
+    positions.iter().fold(0, |acc, pos| {
+        let diff = (*pos as isize) - (final_position as isize);
+        let fuel_cost = (1..=diff.unsigned_abs()).sum::<usize>();
+        acc + fuel_cost
+    })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day07Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Vec<usize>, Day07Error> {
+        // There's only one line in the input
+        let positions_str = match input.lines().next() {
+            Some(line_result) => line_result?,
+            None => return Err(Day07Error::Empty),
+        };
+
+        // Parse positions, there's always at least one since splitting never yields nothing
+        let positions: Vec<usize> = positions_str
+            .split(',')
+            .map(|a| {
+                error::parse_token(0, &positions_str, a, |position, token| {
+                    Day07Error::InvalidPosition { position, token }
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(positions)
+    }
+
+    fn part1(positions: &Vec<usize>) -> Result<usize, Day07Error> {
+        let min_pos = *positions.iter().min().unwrap();
+        let max_pos = *positions.iter().max().unwrap();
+
+        // Find the position that needs the least fuel
+        let min_fuel = (min_pos..=max_pos)
+            .map(|pos| calc_fuel_constant(positions, pos))
+            .min()
+            .unwrap();
+
+        Ok(min_fuel)
+    }
+
+    fn part2(positions: &Vec<usize>) -> Result<usize, Day07Error> {
+        let min_pos = *positions.iter().min().unwrap();
+        let max_pos = *positions.iter().max().unwrap();
+
+        // Find the position that needs the least fuel
+        let min_fuel = (min_pos..=max_pos)
+            .map(|pos| calc_fuel_variable(positions, pos))
+            .min()
+            .unwrap();
+
+        Ok(min_fuel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day07::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day07::part1(&input).unwrap(), 37);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day07::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day07::part2(&input).unwrap(), 168);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day07::parse("16,1,,2\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 6: invalid position ''");
+
+        assert!(matches!(
+            Day07::parse("".as_bytes()),
+            Err(Day07Error::Empty)
+        ));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(7);
+        let input = Day07::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day07::part1(&input).unwrap(), 343605);
+        assert_eq!(Day07::part2(&input).unwrap(), 96744904);
+    }
+}
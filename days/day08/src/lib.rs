@@ -0,0 +1,350 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::io::BufRead;
+
+use common::error::Position;
+use common::Solution;
+use itertools::Itertools;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day08Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: expected '<signal patterns> | <output digits>', got '{line}'")]
+    InvalidFormat { position: Position, line: String },
+    #[error("{position}: invalid pattern '{token}', expected distinct segments a to g")]
+    InvalidPattern { position: Position, token: String },
+    #[error("{position}: the signal patterns aren't the ten digits of a seven-segment display")]
+    InvalidSignalPatterns { position: Position },
+    #[error("line {line}: the signal patterns can't be wired to the digits")]
+    Unsolvable { line: usize },
+    #[error("line {line}: output pattern '{pattern}' isn't one of the signal patterns")]
+    UnknownDigit { line: usize, pattern: String },
+}
+
+// Number of segments lit by each digit, sorted
+const DIGIT_LENGTHS: [usize; 10] = [2, 3, 4, 5, 5, 5, 6, 6, 6, 7];
+
+type DigitalPatterns = (Vec<Vec<String>>, Vec<Vec<String>>);
+
+fn get_pattern_difference(pattern1: &str, pattern2: &str) -> Vec<char> {
+    let pattern1_set: HashSet<char> = HashSet::from_iter(pattern1.chars());
+    let pattern2_set: HashSet<char> = HashSet::from_iter(pattern2.chars());
+
+    pattern1_set
+        .difference(&pattern2_set)
+        .map(|p| p.to_owned())
+        .collect()
+}
+
+type PatternTranslator = Box<dyn Fn(&str) -> Option<char>>;
+
+// `None` if the patterns can't be deduced to be a wiring of the ten digits
+fn get_translator(signal_patterns: &[String]) -> Option<PatternTranslator> {
+    let mut signal_map = HashMap::new();
+    let mut translator_map = HashMap::new();
+
+    // We know which pattern is digit 1 because of the fixed length
+    let digit_1_pattern = signal_patterns.iter().find(|&s| s.len() == 2)?.to_string();
+
+    // We know which pattern is digit 4 because of the fixed length
+    let digit_4_pattern = signal_patterns.iter().find(|&s| s.len() == 4)?.to_string();
+
+    // We know which pattern is digit 7 because of the fixed length
+    let digit_7_pattern = signal_patterns.iter().find(|&s| s.len() == 3)?.to_string();
+
+    // We know which pattern is digit 8 because of the fixed length
+    let digit_8_pattern = signal_patterns.iter().find(|&s| s.len() == 7)?.to_string();
+
+    // Get all patterns with 6 segments (0, 6 and 9)
+    let mut patterns_6_segments: Vec<String> = signal_patterns
+        .iter()
+        .filter(|&s| s.len() == 6)
+        .map(|s| s.to_string())
+        .collect();
+
+    // Get all patterns with 5 segments (2, 3 and 5)
+    let patterns_5_segments = signal_patterns
+        .iter()
+        .filter(|&s| s.len() == 5)
+        .map(|s| s.to_string());
+
+    // The segment corresponding to signal `a` can be deduced by the difference between digits 1 and 7
+    let segment_signal_a = *get_pattern_difference(&digit_7_pattern, &digit_1_pattern).first()?;
+    signal_map.insert(segment_signal_a, 'a');
+
+    // The segment corresponding to signal `a` can be deduced by the difference between digits 1 and 6
+    let (digit_6_index, digit_6_pattern) = patterns_6_segments
+        .iter()
+        .enumerate()
+        .filter(|(_i, p)| {
+            let diff = get_pattern_difference(&digit_1_pattern, p);
+
+            // Found the segment corresponding to signal `c`!
+            if diff.len() == 1 {
+                signal_map.insert(diff[0], 'c');
+                true
+            } else {
+                false
+            }
+        })
+        .map(|(i, p)| (i, p.to_string()))
+        .collect::<Vec<(usize, String)>>()
+        .into_iter()
+        .next()?;
+    patterns_6_segments.remove(digit_6_index);
+
+    // The segment corresponding to signal `a` can be deduced by the difference between digits 4 and 0
+    let (digit_0_index, digit_0_pattern) = patterns_6_segments
+        .iter()
+        .enumerate()
+        .filter(|(_i, p)| {
+            let diff = get_pattern_difference(&digit_4_pattern, p);
+
+            // Found the segment corresponding to signal `d`!
+            if diff.len() == 1 {
+                signal_map.insert(diff[0], 'd');
+                true
+            } else {
+                false
+            }
+        })
+        .map(|(i, p)| (i, p.to_string()))
+        .collect::<Vec<(usize, String)>>()
+        .into_iter()
+        .next()?;
+    patterns_6_segments.remove(digit_0_index);
+
+    // 9 is the only 6 segment digit left
+    let digit_9_pattern = patterns_6_segments.pop()?;
+
+    // The segment corresponding to signal `e` can be deduced by the difference between digits 8 and 9
+    let segment_signal_e = *get_pattern_difference(&digit_8_pattern, &digit_9_pattern).first()?;
+    signal_map.insert(segment_signal_e, 'e');
+
+    // Determine what the patterns for 2, 3 and 5 are by the numbers of unmmapped signals we ge on the difference with pattern 8
+    let mut patterns_5_segments_ord: Vec<String> = patterns_5_segments
+        .sorted_by_key(|p| {
+            let diff = get_pattern_difference(&digit_8_pattern, p);
+            diff.into_iter()
+                .filter(|c| !signal_map.contains_key(c))
+                .count()
+        })
+        .collect();
+
+    let digit_2_pattern = patterns_5_segments_ord.pop()?;
+    let digit_3_pattern = patterns_5_segments_ord.pop()?;
+    let digit_5_pattern = patterns_5_segments_ord.pop()?;
+
+    // The segment corresponding to signal `f` can be deduced by the difference between digits 1 and 2
+    let segment_signal_f = *get_pattern_difference(&digit_1_pattern, &digit_2_pattern).first()?;
+    signal_map.insert(segment_signal_f, 'f');
+
+    // The segment corresponding to signal `b` can be deduced by the difference between digits 5 and 3
+    let segment_signal_b = *get_pattern_difference(&digit_5_pattern, &digit_3_pattern).first()?;
+    signal_map.insert(segment_signal_b, 'b');
+
+    // The segment corresponding to signal `g` is the only one left
+    let segment_signal_g = digit_8_pattern
+        .chars()
+        .find(|c| !signal_map.contains_key(c))?;
+    signal_map.insert(segment_signal_g, 'g');
+
+    translator_map.insert(digit_0_pattern, '0');
+    translator_map.insert(digit_1_pattern, '1');
+    translator_map.insert(digit_2_pattern, '2');
+    translator_map.insert(digit_3_pattern, '3');
+    translator_map.insert(digit_4_pattern, '4');
+    translator_map.insert(digit_5_pattern, '5');
+    translator_map.insert(digit_6_pattern, '6');
+    translator_map.insert(digit_7_pattern, '7');
+    translator_map.insert(digit_8_pattern, '8');
+    translator_map.insert(digit_9_pattern, '9');
+
+    Some(Box::new(move |pattern| {
+        translator_map.get(pattern).copied()
+    }))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = DigitalPatterns;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day08Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<DigitalPatterns, Day08Error> {
+        let mut all_signal_patterns = Vec::new();
+        let mut all_output_digits = Vec::new();
+
+        // Read line by line
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            // Split by | and take two fields
+            let fields: Vec<&str> = line.trim().split('|').collect();
+
+            if fields.len() != 2 {
+                return Err(Day08Error::InvalidFormat {
+                    position: Position::line_start(index),
+                    line,
+                });
+            }
+
+            // Split by whitespace, sorting the segments of every pattern
+            let parse_patterns = |patterns_str: &str| {
+                patterns_str
+                    .split_ascii_whitespace()
+                    .map(|p| {
+                        let segments: String = p.chars().sorted().collect();
+                        let valid = segments.chars().all(|c| ('a'..='g').contains(&c))
+                            && segments.chars().tuple_windows().all(|(a, b)| a != b);
+
+                        if valid {
+                            Ok(segments)
+                        } else {
+                            Err(Day08Error::InvalidPattern {
+                                position: Position::of(index, &line, p),
+                                token: p.to_string(),
+                            })
+                        }
+                    })
+                    .collect::<Result<Vec<String>, _>>()
+            };
+            let signal_patterns = parse_patterns(fields[0])?;
+            let output_digits = parse_patterns(fields[1])?;
+
+            let lengths: Vec<usize> = signal_patterns.iter().map(String::len).sorted().collect();
+            if lengths != DIGIT_LENGTHS {
+                return Err(Day08Error::InvalidSignalPatterns {
+                    position: Position::line_start(index),
+                });
+            }
+
+            all_signal_patterns.push(signal_patterns);
+            all_output_digits.push(output_digits);
+        }
+
+        Ok((all_signal_patterns, all_output_digits))
+    }
+
+    fn part1(
+        (_all_signal_patterns, all_output_digits): &DigitalPatterns,
+    ) -> Result<usize, Day08Error> {
+        let num_1478_digits = all_output_digits.iter().fold(0, |acc, output_digits| {
+            // Calculate number of 1, 4, 7 and 8 digits
+            let num_1478_digits = output_digits
+                .iter()
+                .filter(|&p| {
+                    p.len() == 2    // Digit 1
+                        || p.len() == 4 // Digit 4
+                        || p.len() == 3 // Digit 7
+                        || p.len() == 7 // Digit 8
+                })
+                .count();
+
+            acc + num_1478_digits
+        });
+
+        Ok(num_1478_digits)
+    }
+
+    fn part2(
+        (all_signal_patterns, all_output_digits): &DigitalPatterns,
+    ) -> Result<usize, Day08Error> {
+        let mut numbers_sum = 0;
+        for (i, (patterns, numbers)) in all_signal_patterns
+            .iter()
+            .zip(all_output_digits)
+            .enumerate()
+        {
+            let translate =
+                get_translator(patterns).ok_or(Day08Error::Unsolvable { line: i + 1 })?;
+
+            // Build the number digit by digit
+            let mut number = 0;
+            for pattern in numbers {
+                let digit = translate(pattern).ok_or_else(|| Day08Error::UnknownDigit {
+                    line: i + 1,
+                    pattern: pattern.clone(),
+                })?;
+
+                number = number * 10 + digit.to_digit(10).unwrap() as usize;
+            }
+
+            numbers_sum += number;
+        }
+
+        Ok(numbers_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day08::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day08::part1(&input).unwrap(), 26);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day08::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day08::part2(&input).unwrap(), 61229);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error =
+            Day08::parse("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab\n".as_bytes())
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected '<signal patterns> | <output digits>', got 'acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab'"
+        );
+
+        let error = Day08::parse("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeh cdbaf\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 74: invalid pattern 'cdfeh', expected distinct segments a to g"
+        );
+
+        let input = Day08::parse(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb ag cdbaf\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            Day08::part2(&input).unwrap_err().to_string(),
+            "line 1: output pattern 'ag' isn't one of the signal patterns"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(8);
+        let input = Day08::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day08::part1(&input).unwrap(), 473);
+        assert_eq!(Day08::part2(&input).unwrap(), 1097568);
+    }
+}
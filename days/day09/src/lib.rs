@@ -0,0 +1,153 @@
+use std::io;
+use std::io::BufRead;
+
+use common::grid::{Grid, ParseGridError};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day09Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Grid(#[from] ParseGridError),
+}
+
+#[derive(Debug)]
+pub struct HeightMap {
+    heights: Grid<u8>,
+}
+
+impl HeightMap {
+    fn new(heights: Grid<u8>) -> Self {
+        HeightMap { heights }
+    }
+
+    fn get_low_points(&self) -> Vec<(usize, usize)> {
+        // Every adjacent height must be strictly higher
+        self.heights
+            .indexed_iter()
+            .filter(|&(pos, height)| {
+                self.heights
+                    .neighbors4(pos)
+                    .all(|adj| self.heights[adj] > *height)
+            })
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    fn get_basin_sizes(&self) -> Vec<usize> {
+        let mut basin_sizes = Vec::new();
+
+        // Points already in a basin, so lookups aren't repeated
+        let mut in_basin = Grid::new(self.heights.width(), self.heights.height(), false);
+
+        // Each low point has a basin
+        for low_point in self.get_low_points() {
+            let mut basin_size = 1;
+            in_basin[low_point] = true;
+
+            // Setup lookup stack
+            let mut lookup_stack = vec![low_point];
+
+            while let Some(pos) = lookup_stack.pop() {
+                let height = self.heights[pos];
+
+                // Flow goes up from the low point until reaching a height of 9
+                for adj in self.heights.neighbors4(pos) {
+                    let adj_height = self.heights[adj];
+                    if adj_height < 9 && adj_height > height && !in_basin[adj] {
+                        in_basin[adj] = true;
+                        basin_size += 1;
+                        lookup_stack.push(adj);
+                    }
+                }
+            }
+
+            basin_sizes.push(basin_size);
+        }
+
+        basin_sizes
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = HeightMap;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day09Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day09Error> {
+        // Each char is a height value
+        let heights = Grid::parse(input, |c| c.to_digit(10).map(|h| h as u8))?;
+
+        Ok(HeightMap::new(heights))
+    }
+
+    fn part1(height_map: &Self::Input) -> Result<usize, Day09Error> {
+        // Sum riks levels for all low points
+        Ok(height_map
+            .get_low_points()
+            .into_iter()
+            .fold(0, |acc, low_point| {
+                acc + (height_map.heights[low_point] as usize) + 1
+            }))
+    }
+
+    fn part2(height_map: &Self::Input) -> Result<usize, Day09Error> {
+        let mut basin_sizes = height_map.get_basin_sizes();
+        basin_sizes.sort_by_key(|b| usize::MAX - *b);
+
+        Ok(basin_sizes.into_iter().take(3).product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day09::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day09::part1(&input).unwrap(), 15);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day09::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day09::part2(&input).unwrap(), 1134);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day09::parse("2199\n39a7\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: invalid cell 'a'");
+
+        let error = Day09::parse("2199\n398\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 4 cells, found 3"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(9);
+        let input = Day09::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day09::part1(&input).unwrap(), 537);
+        assert_eq!(Day09::part2(&input).unwrap(), 1142757);
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::LinkedList;
+use std::io;
+use std::io::BufRead;
+
+use common::error::Position;
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day10Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: invalid character '{token}', expected one of ()[]{{}}<>")]
+    InvalidCharacter { position: Position, token: char },
+    #[error("there are no incomplete lines to score")]
+    NoIncompleteLines,
+}
+
+// Illegal scores
+static ILLEGAL_PARENTHESIS_SCORE: usize = 3;
+static ILLEGAL_SQUARE_BRACKET_SCORE: usize = 57;
+static ILLEGAL_BRACE_SCORE: usize = 1197;
+static ILLEGAL_ANGLED_BRACKET_SCORE: usize = 25137;
+
+// Completion scores
+static PARENTHESIS_COMPLETION_POINTS: usize = 1;
+static SQUARE_BRACKET_COMPLETION_POINTS: usize = 2;
+static BRACE_COMPLETION_POINTS: usize = 3;
+static ANGLED_BRACKET_COMPLETION_POINTS: usize = 4;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<char>>;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day10Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day10Error> {
+        let mut syntax_lines = Vec::new();
+
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            let syntax_line: Vec<char> = line.chars().collect();
+            if let Some(column) = syntax_line.iter().position(|c| !"()[]{}<>".contains(*c)) {
+                return Err(Day10Error::InvalidCharacter {
+                    position: Position::new(index + 1, column + 1),
+                    token: syntax_line[column],
+                });
+            }
+
+            syntax_lines.push(syntax_line);
+        }
+
+        Ok(syntax_lines)
+    }
+
+    fn part1(syntax_lines: &Self::Input) -> Result<usize, Day10Error> {
+        // Score for part 1
+        let mut syntax_error_score = 0;
+
+        for syntax_line in syntax_lines {
+            let mut syntax_stack = LinkedList::new();
+            for character in syntax_line {
+                match *character {
+                    // Opening characters
+                    '(' => syntax_stack.push_back('('),
+                    '[' => syntax_stack.push_back('['),
+                    '{' => syntax_stack.push_back('{'),
+                    '<' => syntax_stack.push_back('<'),
+
+                    // Closing characters
+                    ')' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('(') {
+                            syntax_error_score += ILLEGAL_PARENTHESIS_SCORE;
+                            break;
+                        }
+                    }
+                    ']' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('[') {
+                            syntax_error_score += ILLEGAL_SQUARE_BRACKET_SCORE;
+                            break;
+                        }
+                    }
+                    '}' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('{') {
+                            syntax_error_score += ILLEGAL_BRACE_SCORE;
+                            break;
+                        }
+                    }
+                    '>' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('<') {
+                            syntax_error_score += ILLEGAL_ANGLED_BRACKET_SCORE;
+                            break;
+                        }
+                    }
+
+                    _ => unreachable!("characters are validated while parsing"),
+                };
+            }
+        }
+
+        Ok(syntax_error_score)
+    }
+
+    fn part2(syntax_lines: &Self::Input) -> Result<usize, Day10Error> {
+        // Score for part 1
+        let mut completion_scores = Vec::new();
+
+        for syntax_line in syntax_lines {
+            let mut syntax_stack = LinkedList::new();
+            for character in syntax_line {
+                match *character {
+                    // Opening characters
+                    '(' => syntax_stack.push_back('('),
+                    '[' => syntax_stack.push_back('['),
+                    '{' => syntax_stack.push_back('{'),
+                    '<' => syntax_stack.push_back('<'),
+
+                    // Closing characters
+                    ')' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('(') {
+                            syntax_stack.clear();
+                            break;
+                        }
+                    }
+                    ']' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('[') {
+                            syntax_stack.clear();
+                            break;
+                        }
+                    }
+                    '}' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('{') {
+                            syntax_stack.clear();
+                            break;
+                        }
+                    }
+                    '>' => {
+                        // A closing character with nothing open is illegal too
+                        if syntax_stack.pop_back() != Some('<') {
+                            syntax_stack.clear();
+                            break;
+                        }
+                    }
+
+                    _ => unreachable!("characters are validated while parsing"),
+                };
+            }
+
+            if syntax_stack.is_empty() {
+                continue;
+            }
+
+            let mut completion_score = 0;
+            while let Some(character) = syntax_stack.pop_back() {
+                match character {
+                    '(' => completion_score = completion_score * 5 + PARENTHESIS_COMPLETION_POINTS,
+                    '[' => {
+                        completion_score = completion_score * 5 + SQUARE_BRACKET_COMPLETION_POINTS
+                    }
+                    '{' => completion_score = completion_score * 5 + BRACE_COMPLETION_POINTS,
+                    '<' => {
+                        completion_score = completion_score * 5 + ANGLED_BRACKET_COMPLETION_POINTS
+                    }
+                    // Will never happen
+                    _ => unreachable!("characters are validated while parsing"),
+                };
+            }
+            completion_scores.push(completion_score);
+        }
+
+        if completion_scores.is_empty() {
+            return Err(Day10Error::NoIncompleteLines);
+        }
+
+        completion_scores.sort_unstable();
+        Ok(completion_scores[completion_scores.len() / 2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day10::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day10::part1(&input).unwrap(), 26397);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day10::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day10::part2(&input).unwrap(), 288957);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day10::parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>x\n".as_bytes())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 22: invalid character 'x', expected one of ()[]{}<>"
+        );
+
+        let input = Day10::parse("{([(<{}[<>[]}>{[]{[(<()>\n".as_bytes()).unwrap();
+        assert!(matches!(
+            Day10::part2(&input),
+            Err(Day10Error::NoIncompleteLines)
+        ));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(10);
+        let input = Day10::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day10::part1(&input).unwrap(), 387363);
+        assert_eq!(Day10::part2(&input).unwrap(), 4330777059);
+    }
+}
@@ -0,0 +1,171 @@
+use std::io;
+use std::io::BufRead;
+
+use common::grid::{Grid, ParseGridError};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day11Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Grid(#[from] ParseGridError),
+}
+
+#[derive(Debug, Clone)]
+pub struct Consortium {
+    energy_levels: Grid<u8>,
+}
+
+impl Consortium {
+    fn new(energy_levels: Grid<u8>) -> Self {
+        Consortium { energy_levels }
+    }
+
+    fn step(&mut self) -> usize {
+        let mut flash_stack = Vec::new();
+
+        // Increase all energy levels
+        for pos in self.energy_levels.positions() {
+            self.energy_levels[pos] += 1;
+            if self.energy_levels[pos] > 9 {
+                flash_stack.push(pos);
+            }
+        }
+
+        // Do flashes
+        let mut nflashes = 0;
+        while let Some(octopus_pos) = flash_stack.pop() {
+            // Reset energy level
+            self.energy_levels[octopus_pos] = 0;
+            nflashes += 1;
+
+            // Check adjacent octopi
+            for adj in self.energy_levels.neighbors8(octopus_pos) {
+                let energy_level = &mut self.energy_levels[adj];
+
+                // Increase energy levels of adjacent octopi (if they haven't flashed)
+                if *energy_level > 0 {
+                    *energy_level += 1;
+                }
+
+                // Octopi flash once, when they first go over 9
+                if *energy_level == 10 {
+                    flash_stack.push(adj);
+                }
+            }
+        }
+
+        nflashes
+    }
+
+    fn all_have_flashed(&self) -> bool {
+        self.energy_levels.iter().all(|&lvl| lvl == 0)
+    }
+
+    fn simulate(&mut self, steps: usize) -> usize {
+        let mut nflashes = 0;
+        for _i in 1..=steps {
+            nflashes += self.step();
+        }
+
+        nflashes
+    }
+
+    fn simulate_until_all_flash(&mut self) -> usize {
+        let mut nsteps = 0;
+        while !self.all_have_flashed() {
+            self.step();
+            nsteps += 1;
+        }
+
+        nsteps
+    }
+}
+
+impl std::fmt::Display for Consortium {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.energy_levels)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Consortium;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day11Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day11Error> {
+        let energy_levels = Grid::parse(input, |lvl| lvl.to_digit(10).map(|d| d as u8))?;
+
+        Ok(Consortium::new(energy_levels))
+    }
+
+    fn part1(consortium: &Self::Input) -> Result<usize, Day11Error> {
+        let mut consortium = consortium.clone();
+
+        Ok(consortium.simulate(100))
+    }
+
+    fn part2(consortium: &Self::Input) -> Result<usize, Day11Error> {
+        let mut consortium = consortium.clone();
+
+        Ok(consortium.simulate_until_all_flash())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day11::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day11::part1(&input).unwrap(), 1656);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day11::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day11::part2(&input).unwrap(), 195);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day11::parse("5483\n27-5\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: invalid cell '-'");
+
+        let error = Day11::parse("5483\n27455\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 4 cells, found 5"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(11);
+        let input = Day11::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day11::part1(&input).unwrap(), 1683);
+        assert_eq!(Day11::part2(&input).unwrap(), 788);
+    }
+}
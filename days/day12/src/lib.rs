@@ -0,0 +1,330 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, LinkedList};
+use std::io;
+use std::io::BufRead;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use common::error::Position;
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day12Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: expected '<cave>-<cave>', got '{line}'")]
+    InvalidFormat { position: Position, line: String },
+    #[error("{position}: invalid cave '{token}', expected all lowercase or all uppercase letters")]
+    InvalidCave { position: Position, token: String },
+    #[error("there's no connection to the start cave")]
+    MissingStart,
+}
+
+#[derive(Debug)]
+pub enum Cave {
+    Start,
+    Small(String),
+    Big(String),
+    End,
+}
+
+impl FromStr for Cave {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" => Ok(Self::Start),
+            "end" => Ok(Self::End),
+            "" => Err(()),
+            name => {
+                if name.chars().all(|c| c.is_ascii_lowercase()) {
+                    // All lowercase means it's a small cave
+                    Ok(Self::Small(name.to_string()))
+                } else if name.chars().all(|c| c.is_ascii_uppercase()) {
+                    // All uppercase means it's a big cave
+                    Ok(Self::Big(name.to_string()))
+                } else {
+                    Err(())
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CaveNode {
+    cave: Cave,
+    connections: Vec<Rc<RefCell<CaveNode>>>,
+}
+
+impl CaveNode {
+    fn new(cave: Cave) -> Self {
+        CaveNode {
+            cave,
+            connections: Vec::new(),
+        }
+    }
+
+    fn add_connection(&mut self, other_node: Rc<RefCell<CaveNode>>) {
+        self.connections.push(other_node);
+    }
+}
+
+#[derive(Debug)]
+pub struct CaveSystem {
+    start: Rc<RefCell<CaveNode>>,
+}
+
+type PathList = Vec<Vec<Rc<RefCell<CaveNode>>>>;
+impl CaveSystem {
+    fn new(start: Rc<RefCell<CaveNode>>) -> Self {
+        CaveSystem { start }
+    }
+
+    fn find_duplicate(caves: &[String]) -> Option<String> {
+        let mut caves_aux = Vec::new();
+
+        for cave in caves {
+            if caves_aux.contains(cave) {
+                return Some(cave.clone());
+            }
+
+            caves_aux.push(cave.clone());
+        }
+
+        None
+    }
+
+    fn get_all_paths(&self) -> PathList {
+        let mut complete_paths_list = PathList::new();
+
+        let mut path_stack = LinkedList::new();
+        path_stack.push_back(vec![Rc::clone(&self.start)]);
+
+        while let Some(path) = path_stack.pop_back() {
+            // If the path is complete, add it to the list and get the next one
+            if let Cave::End = path.last().unwrap().borrow().cave {
+                complete_paths_list.push(path.clone());
+                continue;
+            }
+
+            // List the small caves that were visited by this path
+            let small_caves_visited: Vec<String> = path
+                .iter()
+                .filter_map(|node| match &node.borrow().cave {
+                    Cave::Small(name) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect();
+
+            for connection in &path.last().unwrap().borrow().connections {
+                // If this is start, skip
+                if let Cave::Start = &connection.borrow().cave {
+                    continue;
+                }
+
+                // If this is a small cave, check if it was visited in this path before
+                if let Cave::Small(name) = &connection.borrow().cave {
+                    if small_caves_visited.contains(name) {
+                        continue;
+                    }
+                }
+
+                // Extend the path with this node
+                let mut extended_path = path.clone();
+                extended_path.push(Rc::clone(connection));
+
+                // Push the extended path to the stack
+                path_stack.push_back(extended_path);
+            }
+        }
+
+        complete_paths_list
+    }
+
+    fn get_all_paths_extra_time(&self) -> PathList {
+        let mut complete_paths_list = PathList::new();
+
+        let mut path_stack = LinkedList::new();
+        path_stack.push_back(vec![Rc::clone(&self.start)]);
+
+        while let Some(path) = path_stack.pop_back() {
+            // If the path is complete, add it to the list and get the next one
+            if let Cave::End = path.last().unwrap().borrow().cave {
+                complete_paths_list.push(path.clone());
+                continue;
+            }
+
+            // List the small caves that were visited by this path
+            let small_caves_visited: Vec<String> = path
+                .iter()
+                .filter_map(|node| match &node.borrow().cave {
+                    Cave::Small(name) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect();
+
+            // Check if we previously visited a small cave twice
+            let special_small_cave = Self::find_duplicate(&small_caves_visited);
+
+            for connection in &path.last().unwrap().borrow().connections {
+                // If this is start, skip
+                if let Cave::Start = &connection.borrow().cave {
+                    continue;
+                }
+
+                // If this is a small cave, check if it was visited in this path before
+                if let Cave::Small(name) = &connection.borrow().cave {
+                    if small_caves_visited.contains(name) {
+                        // If we alredy visited a small cave twice, skip
+                        if special_small_cave.is_some() {
+                            continue;
+                        }
+                    }
+                }
+
+                // Extend the path with this node
+                let mut extended_path = path.clone();
+                extended_path.push(Rc::clone(connection));
+
+                // Push the extended path to the stack
+                path_stack.push_back(extended_path);
+            }
+        }
+
+        complete_paths_list
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = CaveSystem;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day12Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day12Error> {
+        let mut node_map = HashMap::new();
+
+        // Read line by line
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            // Split by '-' into two caves
+            let caves: Vec<&str> = line.trim().split('-').collect();
+
+            if caves.len() != 2 {
+                return Err(Day12Error::InvalidFormat {
+                    position: Position::line_start(index),
+                    line,
+                });
+            }
+
+            let cave1_str = caves[0];
+            let cave2_str = caves[1];
+
+            let parse_cave = |cave_str: &str| {
+                Cave::from_str(cave_str).map_err(|_| Day12Error::InvalidCave {
+                    position: Position::of(index, &line, cave_str),
+                    token: cave_str.to_string(),
+                })
+            };
+            let cave1 = parse_cave(cave1_str)?;
+            let cave2 = parse_cave(cave2_str)?;
+
+            let cave_node1;
+            let cave_node2;
+            {
+                let cave_entry1 = node_map
+                    .entry(cave1_str.to_string())
+                    .or_insert_with(|| Rc::new(RefCell::new(CaveNode::new(cave1))));
+                cave_node1 = Rc::clone(cave_entry1);
+            }
+            {
+                let cave_entry2 = node_map
+                    .entry(cave2_str.to_string())
+                    .or_insert_with(|| Rc::new(RefCell::new(CaveNode::new(cave2))));
+                cave_node2 = Rc::clone(cave_entry2);
+            }
+
+            cave_node1
+                .borrow_mut()
+                .add_connection(Rc::clone(&cave_node2));
+            cave_node2
+                .borrow_mut()
+                .add_connection(Rc::clone(&cave_node1));
+        }
+
+        let start_node = Rc::clone(node_map.get("start").ok_or(Day12Error::MissingStart)?);
+
+        Ok(CaveSystem::new(start_node))
+    }
+
+    fn part1(cave_system: &Self::Input) -> Result<usize, Day12Error> {
+        Ok(cave_system.get_all_paths().len())
+    }
+
+    fn part2(cave_system: &Self::Input) -> Result<usize, Day12Error> {
+        Ok(cave_system.get_all_paths_extra_time().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day12::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day12::part1(&input).unwrap(), 10);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day12::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day12::part2(&input).unwrap(), 36);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day12::parse("start-A\nA-b-c\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected '<cave>-<cave>', got 'A-b-c'"
+        );
+
+        let error = Day12::parse("start-A\nA-bC\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid cave 'bC', expected all lowercase or all uppercase letters"
+        );
+
+        assert!(matches!(
+            Day12::parse("A-end\n".as_bytes()),
+            Err(Day12Error::MissingStart)
+        ));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(12);
+        let input = Day12::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day12::part1(&input).unwrap(), 5157);
+        assert_eq!(Day12::part2(&input).unwrap(), 144309);
+    }
+}
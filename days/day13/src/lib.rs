@@ -0,0 +1,303 @@
+use std::fmt::Display;
+use std::io;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use common::error::{self, Position};
+use common::grid::Grid;
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day13Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: expected 'x,y', got '{line}'")]
+    InvalidDot { position: Position, line: String },
+    #[error("{position}: invalid coordinate '{token}'")]
+    InvalidCoordinate { position: Position, token: String },
+    #[error("{position}: invalid fold instruction '{token}', expected 'fold along x=<n>' or 'fold along y=<n>'")]
+    InvalidInstruction { position: Position, token: String },
+    #[error("there are no fold instructions")]
+    NoInstructions,
+}
+
+#[derive(Debug)]
+pub enum FoldInstruction {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl FromStr for FoldInstruction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (axis, pos) = s.split_once('=').ok_or(())?;
+        let pos: usize = pos.parse().map_err(|_| ())?;
+
+        match axis {
+            "x" => Ok(Self::Horizontal(pos)),
+            "y" => Ok(Self::Vertical(pos)),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TransparentPaper {
+    dots: Grid<bool>,
+}
+
+impl TransparentPaper {
+    fn new(dimension_x: usize, dimension_y: usize, dots_pos: Vec<(usize, usize)>) -> Self {
+        let mut dots = Grid::new(dimension_x, dimension_y, false);
+        for (pos_x, pos_y) in dots_pos {
+            dots[(pos_y, pos_x)] = true;
+        }
+
+        TransparentPaper { dots }
+    }
+
+    fn fold_horizontally(&mut self, line: usize) {
+        let mut folded = Grid::new(line, self.dots.height(), false);
+
+        // Overlay the mirrored dots from the right of the fold line
+        for pos_y in 0..self.dots.height() {
+            let row = self.dots.row(pos_y);
+            for (pos_x, dot) in folded.row_mut(pos_y).iter_mut().enumerate() {
+                *dot = row.get(pos_x).copied().unwrap_or(false)
+                    || row.get(2 * line - pos_x).copied().unwrap_or(false);
+            }
+        }
+
+        self.dots = folded;
+    }
+
+    fn fold_vertically(&mut self, line: usize) {
+        let mut folded = Grid::new(self.dots.width(), line, false);
+
+        // Overlay the mirrored rows from below the fold line, the paper may end before either
+        let height = self.dots.height();
+        for pos_y in 0..line.min(height) {
+            let row = self.dots.row(pos_y);
+            let mirrored = (2 * line - pos_y < height).then(|| self.dots.row(2 * line - pos_y));
+            for (pos_x, dot) in folded.row_mut(pos_y).iter_mut().enumerate() {
+                *dot = row[pos_x] || mirrored.is_some_and(|m| m[pos_x]);
+            }
+        }
+
+        self.dots = folded;
+    }
+
+    fn fold(&mut self, instruction: &FoldInstruction) {
+        match instruction {
+            FoldInstruction::Horizontal(line) => self.fold_horizontally(*line),
+            FoldInstruction::Vertical(line) => self.fold_vertically(*line),
+        }
+    }
+
+    fn dot_count(&self) -> usize {
+        self.dots.iter().filter(|&&dot| dot).count()
+    }
+}
+
+impl Display for TransparentPaper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let paper_str = self.dots.render(|&dot| if dot { '#' } else { ' ' });
+
+        write!(f, "{}", paper_str)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (TransparentPaper, Vec<FoldInstruction>);
+    type Output1 = usize;
+    type Output2 = TransparentPaper;
+    type Error = Day13Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day13Error> {
+        let mut dots_pos = Vec::new();
+        let mut instructions = Vec::new();
+
+        let mut lines_iter = input.lines().enumerate();
+
+        // Read dots
+        let mut max_x = 0;
+        let mut max_y = 0;
+        for (index, line_result) in lines_iter.by_ref() {
+            let line = line_result?;
+
+            // If it's an empty line, we finished reading the dots
+            if line.is_empty() {
+                break;
+            }
+
+            let fields: Vec<&str> = line.split(',').collect();
+            if fields.len() != 2 {
+                return Err(Day13Error::InvalidDot {
+                    position: Position::line_start(index),
+                    line,
+                });
+            }
+
+            let dot_pos: Vec<usize> = fields
+                .iter()
+                .map(|s| {
+                    error::parse_token(index, &line, s, |position, token| {
+                        Day13Error::InvalidCoordinate { position, token }
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+
+            if dot_pos[0] > max_x {
+                max_x = dot_pos[0];
+            }
+
+            if dot_pos[1] > max_y {
+                max_y = dot_pos[1];
+            }
+
+            dots_pos.push((dot_pos[0], dot_pos[1]));
+        }
+
+        // Read instructions
+        for (index, line_result) in lines_iter {
+            let line = line_result?;
+
+            // If it's an empty line, we've nothing more to read
+            if line.is_empty() {
+                break;
+            }
+
+            let invalid_instruction = || Day13Error::InvalidInstruction {
+                position: Position::line_start(index),
+                token: line.clone(),
+            };
+
+            let instruction_str = line
+                .strip_prefix("fold along ")
+                .ok_or_else(invalid_instruction)?;
+            let instruction =
+                FoldInstruction::from_str(instruction_str).map_err(|_| invalid_instruction())?;
+
+            instructions.push(instruction);
+        }
+
+        let paper = TransparentPaper::new(max_x + 1, max_y + 1, dots_pos);
+        Ok((paper, instructions))
+    }
+
+    fn part1((paper, instructions): &Self::Input) -> Result<usize, Day13Error> {
+        let mut paper = paper.clone();
+        paper.fold(instructions.first().ok_or(Day13Error::NoInstructions)?);
+
+        Ok(paper.dot_count())
+    }
+
+    fn part2((paper, instructions): &Self::Input) -> Result<TransparentPaper, Day13Error> {
+        let mut paper = paper.clone();
+        for instruction in instructions {
+            paper.fold(instruction);
+        }
+
+        Ok(paper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day13::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day13::part1(&input).unwrap(), 17);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day13::parse(EXAMPLE.as_bytes()).unwrap();
+        let paper = [
+            "#####", "#   #", "#   #", "#   #", "#####", "     ", "     ",
+        ]
+        .join("\n");
+
+        assert_eq!(Day13::part2(&input).unwrap().to_string(), paper);
+    }
+
+    #[test]
+    fn fold_beyond_paper() {
+        // The dots only reach x=3 and y=1, so the paper ends before both fold lines
+        let input =
+            Day13::parse("3,0\n0,1\n\nfold along x=5\nfold along y=4\n".as_bytes()).unwrap();
+        let paper = ["   # ", "#    ", "     ", "     "].join("\n");
+
+        assert_eq!(Day13::part2(&input).unwrap().to_string(), paper);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day13::parse("6,10\n0,x\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid coordinate 'x'"
+        );
+
+        let error = Day13::parse("6,10\n\nfold along z=7\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid fold instruction 'fold along z=7', expected 'fold along x=<n>' or 'fold along y=<n>'"
+        );
+
+        let input = Day13::parse("6,10\n".as_bytes()).unwrap();
+        assert!(matches!(
+            Day13::part1(&input),
+            Err(Day13Error::NoInstructions)
+        ));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(13);
+        let input = Day13::parse(input.as_slice()).unwrap();
+        let paper = [
+            "###   ##  #### #    ###  #  # #### ###  ",
+            "#  # #  #    # #    #  # #  # #    #  # ",
+            "#  # #      #  #    ###  #### ###  #  # ",
+            "###  # ##  #   #    #  # #  # #    ###  ",
+            "# #  #  # #    #    #  # #  # #    #    ",
+            "#  #  ### #### #### ###  #  # #    #    ",
+        ]
+        .join("\n");
+
+        assert_eq!(Day13::part1(&input).unwrap(), 729);
+        assert_eq!(Day13::part2(&input).unwrap().to_string(), paper);
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::BufRead;
+
+use common::error::Position;
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day14Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("the polymer template is empty")]
+    EmptyTemplate,
+    #[error("{position}: expected '<pair> -> <element>', got '{line}'")]
+    InvalidRule { position: Position, line: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct Polymerizer {
+    polymer_pairs: HashMap<String, usize>,
+    element_frequency: HashMap<char, usize>,
+    rules: HashMap<String, char>,
+}
+
+impl Polymerizer {
+    fn new(initial_polymer: String, raw_rules: Vec<(String, char)>) -> Self {
+        let polymer_pairs = initial_polymer
+            .chars()
+            .collect::<Vec<char>>()
+            .windows(2)
+            .fold(HashMap::new(), |mut hm, p| {
+                let pair = String::from_iter(p);
+                *hm.entry(pair).or_insert(0) += 1;
+                hm
+            });
+
+        let element_frequency = initial_polymer.chars().fold(HashMap::new(), |mut hm, c| {
+            *hm.entry(c).or_insert(0) += 1;
+            hm
+        });
+
+        let rules = raw_rules.into_iter().fold(HashMap::new(), |mut hm, r| {
+            hm.insert(r.0, r.1);
+            hm
+        });
+
+        Polymerizer {
+            polymer_pairs,
+            element_frequency,
+            rules,
+        }
+    }
+
+    fn step(&mut self) {
+        let mut new_pairs = self.polymer_pairs.clone();
+
+        for (pair, count) in &self.polymer_pairs {
+            if *count == 0 {
+                continue;
+            }
+
+            // Separate the elements of each pair
+            let mut chars_iter = pair.chars();
+            let first_elem = chars_iter.next().unwrap();
+            let second_elem = chars_iter.next().unwrap();
+
+            // Get the new element from the rule table, pairs without a rule are left alone
+            let Some(&new_elem) = self.rules.get(pair) else {
+                continue;
+            };
+
+            // Update the frequency map
+            *self.element_frequency.entry(new_elem).or_insert(0) += count;
+
+            // Empty the count of this pair
+            *new_pairs.entry(pair.to_string()).or_insert(*count) -= count;
+
+            // Increase the count of the two new pairs
+            let new_pair_left = String::from_iter([first_elem, new_elem]);
+            let new_pair_right = String::from_iter([new_elem, second_elem]);
+            *new_pairs.entry(new_pair_left).or_insert(0) += count;
+            *new_pairs.entry(new_pair_right).or_insert(0) += count;
+        }
+
+        self.polymer_pairs = new_pairs;
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Polymerizer;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day14Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day14Error> {
+        let mut lines_iter = input.lines().enumerate();
+
+        // First line is the initial polymer
+        let polymer = match lines_iter.next() {
+            Some((_, line_result)) => line_result?,
+            None => String::new(),
+        };
+        if polymer.is_empty() {
+            return Err(Day14Error::EmptyTemplate);
+        }
+
+        // Read the rules
+        let raw_rules = lines_iter
+            .filter(|(_, l)| !matches!(l, Ok(l) if l.is_empty()))
+            .map(|(index, r)| {
+                let r = r?;
+                let fields: Vec<&str> = r.split("->").map(|f| f.trim()).collect();
+
+                // A pair of two elements is replaced by a single element
+                match fields[..] {
+                    [left_side, right_side]
+                        if left_side.chars().count() == 2 && right_side.chars().count() == 1 =>
+                    {
+                        Ok((left_side.to_string(), right_side.chars().next().unwrap()))
+                    }
+                    _ => Err(Day14Error::InvalidRule {
+                        position: Position::line_start(index),
+                        line: r.clone(),
+                    }),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Polymerizer::new(polymer, raw_rules))
+    }
+
+    fn part1(polymerizer: &Self::Input) -> Result<usize, Day14Error> {
+        let mut polymerizer = polymerizer.clone();
+
+        for _ in 0..10 {
+            polymerizer.step();
+        }
+
+        let max_freq = polymerizer
+            .element_frequency
+            .iter()
+            .max_by_key(|f| f.1)
+            .unwrap();
+        let min_freq = polymerizer
+            .element_frequency
+            .iter()
+            .min_by_key(|f| f.1)
+            .unwrap();
+
+        Ok(max_freq.1 - min_freq.1)
+    }
+
+    fn part2(polymerizer: &Self::Input) -> Result<usize, Day14Error> {
+        let mut polymerizer = polymerizer.clone();
+
+        for _ in 0..40 {
+            polymerizer.step();
+        }
+
+        let max_freq = polymerizer
+            .element_frequency
+            .iter()
+            .max_by_key(|f| f.1)
+            .unwrap();
+        let min_freq = polymerizer
+            .element_frequency
+            .iter()
+            .min_by_key(|f| f.1)
+            .unwrap();
+
+        Ok(max_freq.1 - min_freq.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day14::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day14::part1(&input).unwrap(), 1588);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day14::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day14::part2(&input).unwrap(), 2188189693529);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day14::parse("NNCB\n\nCH -> B\nHHH -> N\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected '<pair> -> <element>', got 'HHH -> N'"
+        );
+
+        assert!(matches!(
+            Day14::parse("\n\nCH -> B\n".as_bytes()),
+            Err(Day14Error::EmptyTemplate)
+        ));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(14);
+        let input = Day14::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day14::part1(&input).unwrap(), 2590);
+        assert_eq!(Day14::part2(&input).unwrap(), 2875665202438);
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::{BinaryHeap, VecDeque};
+use std::io;
+use std::io::BufRead;
+use std::rc::Rc;
+
+use common::grid::{Grid, ParseGridError};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day15Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("the cave map is empty")]
+    Empty,
+    #[error(transparent)]
+    Grid(#[from] ParseGridError),
+    #[error("no path was found through the cave")]
+    NoPath,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct PathNode {
+    position: (usize, usize),        // Position in the cave
+    total_risk: usize,               // Total risk cost to this node
+    distance: usize,                 // Manhattan distance to the goal
+    came_from: Option<Rc<PathNode>>, // Which node this one was measured from
+}
+
+impl PathNode {
+    fn new(
+        position: (usize, usize),
+        total_risk: usize,
+        distance: usize,
+        came_from: Option<Rc<PathNode>>,
+    ) -> Self {
+        PathNode {
+            position,
+            total_risk,
+            distance,
+            came_from,
+        }
+    }
+}
+
+impl Ord for PathNode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let self_total_cost = self.total_risk + self.distance;
+        let other_total_cost = other.total_risk + other.distance;
+
+        // Reverse because a lower cost means higher priority
+        self_total_cost.cmp(&other_total_cost).reverse()
+    }
+}
+
+impl PartialOrd for PathNode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    risk_costs: Grid<u8>,
+}
+
+impl Cave {
+    fn new(risk_costs: Grid<u8>) -> Self {
+        Cave { risk_costs }
+    }
+
+    // Calculates the Manhattan distance between two points
+    fn distance_between(pos1: (usize, usize), pos2: (usize, usize)) -> usize {
+        pos1.0.abs_diff(pos2.0) + pos1.1.abs_diff(pos2.1)
+    }
+
+    // Repeats the cave in both directions, each tile being 1 riskier than the one before it
+    fn extend_tiles(&mut self, x_tiles: usize, y_tiles: usize) {
+        let (ncols, nrows) = (self.risk_costs.width(), self.risk_costs.height());
+
+        self.risk_costs = Grid::from_fn(ncols * x_tiles, nrows * y_tiles, |(row, col)| {
+            let tile = row / nrows + col / ncols;
+            let new_v = self.risk_costs[(row % nrows, col % ncols)] + tile as u8;
+
+            // Risk levels wrap back around to 1 after 9
+            (new_v - 1) % 9 + 1
+        });
+    }
+
+    // Implements the A* algorithm to find the shortest path
+    fn find_shortest_path(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Option<(Vec<(usize, usize)>, usize)> {
+        let mut node_queue = BinaryHeap::new();
+
+        let start_path_node = PathNode::new(start, 0, Self::distance_between(start, goal), None);
+        node_queue.push(Rc::new(start_path_node));
+
+        // Keep track of visited nodes so we don't cycle
+        let mut visited_nodes = Grid::new(self.risk_costs.width(), self.risk_costs.height(), false);
+
+        let mut final_path_node = None;
+        while let Some(node) = node_queue.pop() {
+            visited_nodes[node.position] = true;
+
+            // Check if we reached our goal
+            if node.position == goal {
+                final_path_node = Some(node);
+                break;
+            }
+
+            // Add all adjacent nodes to the queue
+            for adj_pos in self.risk_costs.neighbors4(node.position) {
+                if visited_nodes[adj_pos] {
+                    continue;
+                }
+
+                let new_node = PathNode::new(
+                    adj_pos,
+                    node.total_risk + self.risk_costs[adj_pos] as usize,
+                    Self::distance_between(adj_pos, goal),
+                    Some(Rc::clone(&node)),
+                );
+
+                node_queue.push(Rc::new(new_node));
+            }
+        }
+
+        let mut path_node = &final_path_node?;
+
+        // Save total risk cost of the path
+        let path_risk_cost = path_node.total_risk;
+
+        // Rebuild path
+        let mut shortest_path = VecDeque::new();
+        shortest_path.push_front(path_node.position);
+        while let Some(previous_node) = &path_node.came_from {
+            shortest_path.push_front(previous_node.position);
+            path_node = previous_node;
+        }
+
+        Some((Vec::from(shortest_path), path_risk_cost))
+    }
+
+    fn find_shortest_path_corners(&self) -> Option<(Vec<(usize, usize)>, usize)> {
+        let upper_left_corner = (0, 0);
+        let lower_right_corner = (self.risk_costs.height() - 1, self.risk_costs.width() - 1);
+
+        self.find_shortest_path(upper_left_corner, lower_right_corner)
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Cave;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day15Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day15Error> {
+        let risk_costs = Grid::parse(input, |lvl| lvl.to_digit(10).map(|d| d as u8))?;
+
+        // The cave can't be empty, there would be no corners to go between
+        if risk_costs.is_empty() {
+            return Err(Day15Error::Empty);
+        }
+
+        Ok(Cave::new(risk_costs))
+    }
+
+    fn part1(cave: &Self::Input) -> Result<usize, Day15Error> {
+        let (_path, total_risk_cost) = cave
+            .find_shortest_path_corners()
+            .ok_or(Day15Error::NoPath)?;
+
+        Ok(total_risk_cost)
+    }
+
+    fn part2(cave: &Self::Input) -> Result<usize, Day15Error> {
+        let mut cave = cave.clone();
+        cave.extend_tiles(5, 5);
+
+        let (_path, total_risk_cost) = cave
+            .find_shortest_path_corners()
+            .ok_or(Day15Error::NoPath)?;
+
+        Ok(total_risk_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day15::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day15::part1(&input).unwrap(), 40);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day15::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day15::part2(&input).unwrap(), 315);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day15::parse("1163\n13?8\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: invalid cell '?'");
+
+        let error = Day15::parse("1163\n138\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 4 cells, found 3"
+        );
+
+        assert!(matches!(
+            Day15::parse("".as_bytes()),
+            Err(Day15Error::Empty)
+        ));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(15);
+        let input = Day15::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day15::part1(&input).unwrap(), 410);
+        assert_eq!(Day15::part2(&input).unwrap(), 2809);
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::{self, Display};
+
+use common::error::Position;
+
+use crate::Day16Error;
+
+// Bits of a transmission packed into bytes, most significant bit first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bits {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl Bits {
+    // Parses a line of hexadecimal digits, `line` being its 0-based index for errors
+    pub fn from_hex(line: usize, hex: &str) -> Result<Self, Day16Error> {
+        let mut bits = Bits::default();
+        for (column, c) in hex.chars().enumerate() {
+            let digit = c.to_digit(16).ok_or(Day16Error::InvalidHexDigit {
+                position: Position::new(line + 1, column + 1),
+                token: c,
+            })?;
+            bits.push(digit as usize, 4);
+        }
+
+        Ok(bits)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn bit(&self, offset: usize) -> bool {
+        self.bytes[offset / 8] >> (7 - offset % 8) & 1 == 1
+    }
+
+    // Appends the `len` lowest bits of `value`, which must fit in them
+    fn push(&mut self, value: usize, len: usize) {
+        for i in (0..len).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            self.bytes[self.len / 8] |= ((value >> i & 1) as u8) << (7 - self.len % 8);
+            self.len += 1;
+        }
+    }
+
+    // Appends `value` to a `len` bits field, failing when it doesn't fit
+    pub fn write(
+        &mut self,
+        value: usize,
+        len: usize,
+        field: &'static str,
+    ) -> Result<(), Day16Error> {
+        if value.checked_shr(len as u32).unwrap_or(0) != 0 {
+            return Err(Day16Error::FieldOverflow {
+                field,
+                value,
+                bits: len,
+            });
+        }
+        self.push(value, len);
+
+        Ok(())
+    }
+
+    pub fn extend(&mut self, other: &Bits) {
+        for offset in 0..other.len {
+            self.push(other.bit(offset) as usize, 1);
+        }
+    }
+
+    // Hexadecimal digits of the bits, padded with zeros up to a whole number of bytes
+    pub fn to_hex(&self) -> String {
+        self.bytes
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect()
+    }
+
+    pub fn reader(&self) -> BitReader<'_> {
+        BitReader {
+            bits: self,
+            offset: 0,
+        }
+    }
+}
+
+// The bits as a string of '0' and '1', without any padding
+impl Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for offset in 0..self.len {
+            write!(f, "{}", self.bit(offset) as u8)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Cursor reading fields of a few bits at a time
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bits: &'a Bits,
+    offset: usize,
+}
+
+impl BitReader<'_> {
+    // Offset of the next bit to read from the start of the transmission
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bits.len - self.offset
+    }
+
+    // Whether every bit left is a 0
+    pub fn rest_is_zero(&self) -> bool {
+        (self.offset..self.bits.len).all(|offset| !self.bits.bit(offset))
+    }
+
+    // Reads a `len` bits field as a number, `len` being at most the bits of a `usize`
+    pub fn read(&mut self, len: usize, field: &'static str) -> Result<usize, Day16Error> {
+        if len > self.remaining() {
+            return Err(Day16Error::UnexpectedEnd {
+                offset: self.offset,
+                field,
+            });
+        }
+
+        let value = (self.offset..self.offset + len)
+            .fold(0, |acc, offset| acc << 1 | self.bits.bit(offset) as usize);
+        self.offset += len;
+
+        Ok(value)
+    }
+}
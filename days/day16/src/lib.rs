@@ -0,0 +1,810 @@
+mod bits;
+
+use std::fmt::{self, Display};
+use std::io;
+use std::io::BufRead;
+
+use common::error::Position;
+use common::Solution;
+use thiserror::Error;
+
+pub use bits::{BitReader, Bits};
+
+#[derive(Debug, Error)]
+pub enum Day16Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: invalid hexadecimal digit '{token}'")]
+    InvalidHexDigit { position: Position, token: char },
+    #[error("bit {offset}: the transmission ends in the middle of the {field}")]
+    UnexpectedEnd { offset: usize, field: &'static str },
+    #[error("bit {offset}: invalid operator type ID {id}")]
+    InvalidTypeId { offset: usize, id: u8 },
+    #[error("bit {offset}: literal value doesn't fit in 128 bits")]
+    LiteralOverflow { offset: usize },
+    #[error("{operation} operation overflows 128 bits")]
+    ResultOverflow { operation: Operation },
+    #[error("bit {offset}: sub-packets take {found} bits, but the length field says {expected}")]
+    LengthMismatch {
+        offset: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("{operation} operation needs at least one sub-packet")]
+    NoOperands { operation: Operation },
+    #[error("{operation} operation is only valid between two packets, but got {count}")]
+    InvalidOperandCount { operation: Operation, count: usize },
+    #[error("bit {offset}: packets nested more than {max} deep")]
+    TooDeep { offset: usize, max: usize },
+    #[error("bit {offset}: padding after the last packet isn't all zeros")]
+    NonZeroPadding { offset: usize },
+    #[error("{field} {value} doesn't fit in {bits} bits")]
+    FieldOverflow {
+        field: &'static str,
+        value: usize,
+        bits: usize,
+    },
+}
+
+// Operators nested in each other a transmission can have, deeper ones would overflow the stack
+// of everything walking the packets recursively
+const MAX_DEPTH: usize = 1000;
+
+enum SubPackageSize {
+    Bits(usize),
+    Count(usize),
+}
+
+impl SubPackageSize {
+    fn read(reader: &mut BitReader) -> Result<Self, Day16Error> {
+        match reader.read(1, "length type ID")? {
+            0 => Ok(Self::Bits(reader.read(15, "sub-packets length")?)),
+            _ => Ok(Self::Count(reader.read(11, "sub-packets count")?)),
+        }
+    }
+}
+
+// How an operator packet says where its sub-packets end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    Bits,
+    Count,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LesserThan,
+    Equal,
+}
+
+impl TryFrom<u8> for Operation {
+    type Error = u8;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(Self::Sum),
+            1 => Ok(Self::Product),
+            2 => Ok(Self::Minimum),
+            3 => Ok(Self::Maximum),
+            5 => Ok(Self::GreaterThan),
+            6 => Ok(Self::LesserThan),
+            7 => Ok(Self::Equal),
+            _ => Err(v),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Sum => "sum",
+            Self::Product => "product",
+            Self::Minimum => "min",
+            Self::Maximum => "max",
+            Self::GreaterThan => "gt",
+            Self::LesserThan => "lt",
+            Self::Equal => "eq",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl Operation {
+    // Result of the operation on the values of its sub-packets
+    fn apply(self, values: &[u128]) -> Result<u128, Day16Error> {
+        let operation = self;
+        let no_operands = || Day16Error::NoOperands { operation };
+        let overflow = || Day16Error::ResultOverflow { operation };
+
+        // Comparisons are only valid between two packets
+        let operands = || match values {
+            &[left, right] => Ok((left, right)),
+            _ => Err(Day16Error::InvalidOperandCount {
+                operation,
+                count: values.len(),
+            }),
+        };
+
+        match operation {
+            Self::Sum => values
+                .iter()
+                .try_fold(0u128, |acc, &v| acc.checked_add(v).ok_or_else(overflow)),
+            Self::Product => values
+                .iter()
+                .try_fold(1u128, |acc, &v| acc.checked_mul(v).ok_or_else(overflow)),
+            Self::Minimum => values.iter().copied().min().ok_or_else(no_operands),
+            Self::Maximum => values.iter().copied().max().ok_or_else(no_operands),
+            Self::GreaterThan => operands().map(|(left, right)| (left > right) as u128),
+            Self::LesserThan => operands().map(|(left, right)| (left < right) as u128),
+            Self::Equal => operands().map(|(left, right)| (left == right) as u128),
+        }
+    }
+}
+
+impl From<Operation> for u8 {
+    fn from(operation: Operation) -> Self {
+        match operation {
+            Operation::Sum => 0,
+            Operation::Product => 1,
+            Operation::Minimum => 2,
+            Operation::Maximum => 3,
+            Operation::GreaterThan => 5,
+            Operation::LesserThan => 6,
+            Operation::Equal => 7,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketType {
+    Literal(u128),
+    Operator((Vec<Packet>, Operation)),
+}
+
+#[derive(Debug, Clone)]
+pub struct Packet {
+    version: u8,
+    package_type: Box<PacketType>,
+    // Bit the packet starts at in its transmission, `None` for packets that weren't decoded
+    offset: Option<usize>,
+}
+
+// Packets are equal when their contents are, wherever they were decoded from
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version && self.package_type == other.package_type
+    }
+}
+
+impl Eq for Packet {}
+
+impl Packet {
+    pub fn literal(version: u8, value: u128) -> Self {
+        Packet {
+            version,
+            package_type: Box::new(PacketType::Literal(value)),
+            offset: None,
+        }
+    }
+
+    pub fn operator(version: u8, operation: Operation, packets: Vec<Packet>) -> Self {
+        Packet {
+            version,
+            package_type: Box::new(PacketType::Operator((packets, operation))),
+            offset: None,
+        }
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn package_type(&self) -> &PacketType {
+        &self.package_type
+    }
+
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    // Indented rendering of the packet, with the version, offset and value of every packet in it
+    pub fn tree(&self) -> Tree<'_> {
+        Tree(self)
+    }
+
+    // Appends the packet to `bits`, every operator using `length_type`
+    fn encode(&self, bits: &mut Bits, length_type: LengthType) -> Result<(), Day16Error> {
+        bits.write(self.version as usize, 3, "packet version")?;
+
+        match self.package_type.as_ref() {
+            PacketType::Literal(value) => {
+                bits.write(4, 3, "packet type ID")?;
+
+                // Groups of 4 bits, all but the last one starting with a 1
+                let groups = (u128::BITS - value.leading_zeros()).div_ceil(4).max(1);
+                for group in (0..groups).rev() {
+                    bits.write((group > 0) as usize, 1, "literal group prefix")?;
+                    bits.write((value >> (group * 4) & 0xF) as usize, 4, "literal group")?;
+                }
+            }
+            PacketType::Operator((packets, operation)) => {
+                bits.write(u8::from(*operation) as usize, 3, "packet type ID")?;
+
+                let mut sub_packets = Bits::default();
+                for packet in packets {
+                    packet.encode(&mut sub_packets, length_type)?;
+                }
+
+                match length_type {
+                    LengthType::Bits => {
+                        bits.write(0, 1, "length type ID")?;
+                        bits.write(sub_packets.len(), 15, "sub-packets length")?;
+                    }
+                    LengthType::Count => {
+                        bits.write(1, 1, "length type ID")?;
+                        bits.write(packets.len(), 11, "sub-packets count")?;
+                    }
+                }
+                bits.extend(&sub_packets);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn to_bits(&self, length_type: LengthType) -> Result<Bits, Day16Error> {
+        let mut bits = Bits::default();
+        self.encode(&mut bits, length_type)?;
+
+        Ok(bits)
+    }
+
+    // The packet as a string of '0' and '1', without any padding
+    pub fn to_binary(&self, length_type: LengthType) -> Result<String, Day16Error> {
+        Ok(self.to_bits(length_type)?.to_string())
+    }
+
+    // The packet as a hexadecimal transmission, padded with zeros up to a whole number of bytes
+    pub fn to_hex(&self, length_type: LengthType) -> Result<String, Day16Error> {
+        Ok(self.to_bits(length_type)?.to_hex())
+    }
+
+    // Reads the packet starting at the reader's offset, leaving the reader right after it
+    pub fn read(reader: &mut BitReader) -> Result<Self, Day16Error> {
+        Self::read_nested(reader, 0)
+    }
+
+    // Same as `read`, for a packet inside `depth` operators
+    fn read_nested(reader: &mut BitReader, depth: usize) -> Result<Self, Day16Error> {
+        let start = reader.offset();
+        if depth > MAX_DEPTH {
+            return Err(Day16Error::TooDeep {
+                offset: start,
+                max: MAX_DEPTH,
+            });
+        }
+        let version = reader.read(3, "packet version")? as u8;
+        let packet_type_id = reader.read(3, "packet type ID")? as u8;
+
+        let package_type = match packet_type_id {
+            4 => {
+                // Literal packet, made of groups of 5 bits where the last one starts with a 0
+                let mut value: u128 = 0;
+                loop {
+                    let group = reader.read(5, "literal value")?;
+                    value = value
+                        .checked_mul(16)
+                        .ok_or(Day16Error::LiteralOverflow { offset: start })?
+                        | (group & 0xF) as u128;
+
+                    if group >> 4 == 0 {
+                        break;
+                    }
+                }
+
+                Box::new(PacketType::Literal(value))
+            }
+            op_type => {
+                // Operator packet
+                let operation =
+                    Operation::try_from(op_type).map_err(|id| Day16Error::InvalidTypeId {
+                        offset: start + 3,
+                        id,
+                    })?;
+
+                let packet_size = SubPackageSize::read(reader)?;
+                let packets_start = reader.offset();
+
+                let packets = match packet_size {
+                    SubPackageSize::Bits(bits) => {
+                        let mut packets = Vec::new();
+                        let limit = packets_start + bits;
+                        while reader.offset() < limit {
+                            packets.push(Self::read_nested(reader, depth + 1)?);
+                        }
+
+                        if reader.offset() != limit {
+                            return Err(Day16Error::LengthMismatch {
+                                offset: packets_start,
+                                expected: bits,
+                                found: reader.offset() - packets_start,
+                            });
+                        }
+
+                        packets
+                    }
+                    SubPackageSize::Count(count) => (0..count)
+                        .map(|_| Self::read_nested(reader, depth + 1))
+                        .collect::<Result<Vec<_>, _>>()?,
+                };
+
+                Box::new(PacketType::Operator((packets, operation)))
+            }
+        };
+
+        Ok(Packet {
+            version,
+            package_type,
+            offset: Some(start),
+        })
+    }
+
+    pub fn get_version_sum(&self) -> usize {
+        self.version as usize
+            + match self.package_type.as_ref() {
+                PacketType::Operator((packets, _)) => {
+                    packets.iter().map(|p| p.get_version_sum()).sum()
+                }
+                PacketType::Literal(_) => 0,
+            }
+    }
+
+    pub fn get_result(&self) -> Result<u128, Day16Error> {
+        match self.package_type.as_ref() {
+            PacketType::Literal(value) => Ok(*value),
+            PacketType::Operator((packets, operation)) => {
+                let values = packets
+                    .iter()
+                    .map(|p| p.get_result())
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                operation.apply(&values)
+            }
+        }
+    }
+}
+
+// Smallest packet there is, a literal with a single group
+const MIN_PACKET_BITS: usize = 11;
+
+// Decodes every packet in a line of concatenated packets, `line` being its 0-based index for errors.
+// Bits left after the last packet are padding, which has to be all zeros
+pub fn decode_stream(line: usize, hex: &str) -> Result<Vec<Packet>, Day16Error> {
+    let bits = Bits::from_hex(line, hex)?;
+    let mut reader = bits.reader();
+
+    let mut packets = Vec::new();
+    while !reader.rest_is_zero() {
+        if reader.remaining() < MIN_PACKET_BITS {
+            return Err(Day16Error::NonZeroPadding {
+                offset: reader.offset(),
+            });
+        }
+        packets.push(Packet::read(&mut reader)?);
+    }
+
+    Ok(packets)
+}
+
+// S-expression of the packet, e.g. `(sum (product 6 9) (min 7 8 9))`
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.package_type.as_ref() {
+            PacketType::Literal(value) => write!(f, "{}", value),
+            PacketType::Operator((packets, operation)) => {
+                write!(f, "({}", operation)?;
+                for packet in packets {
+                    write!(f, " {}", packet)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+pub struct Tree<'a>(&'a Packet);
+
+impl Tree<'_> {
+    // Values of `packet` and of every packet in it, in the order they're written, computed in a
+    // single pass. Failures are kept as their message since they're shared with the parents
+    fn values(packet: &Packet, values: &mut Vec<Result<u128, String>>) -> Result<u128, String> {
+        let index = values.len();
+        values.push(Ok(0));
+
+        let value = match packet.package_type.as_ref() {
+            PacketType::Literal(value) => Ok(*value),
+            PacketType::Operator((packets, operation)) => {
+                // Every sub-packet needs its value, even after one of them failed
+                let children: Vec<_> = packets.iter().map(|p| Self::values(p, values)).collect();
+                children
+                    .into_iter()
+                    .collect::<Result<Vec<_>, _>>()
+                    .and_then(|children| operation.apply(&children).map_err(|e| e.to_string()))
+            }
+        };
+        values[index] = value.clone();
+
+        value
+    }
+
+    fn write(
+        packet: &Packet,
+        f: &mut fmt::Formatter<'_>,
+        depth: usize,
+        values: &mut impl Iterator<Item = Result<u128, String>>,
+    ) -> fmt::Result {
+        let value = values.next().ok_or(fmt::Error)?;
+        write!(f, "{:indent$}", "", indent = depth * 2)?;
+        match (packet.package_type.as_ref(), value) {
+            (PacketType::Literal(value), _) => write!(f, "{}", value)?,
+            (PacketType::Operator((_, operation)), Ok(value)) => {
+                write!(f, "{} = {}", operation, value)?
+            }
+            (PacketType::Operator((_, operation)), Err(error)) => {
+                write!(f, "{} failed: {}", operation, error)?
+            }
+        }
+
+        write!(f, "  [version {}", packet.version)?;
+        if let Some(offset) = packet.offset {
+            write!(f, ", bit {}", offset)?;
+        }
+        writeln!(f, "]")?;
+
+        if let PacketType::Operator((packets, _)) = packet.package_type.as_ref() {
+            for packet in packets {
+                Self::write(packet, f, depth + 1, values)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Tree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut values = Vec::new();
+        Self::values(self.0, &mut values).ok();
+
+        Self::write(self.0, f, 0, &mut values.into_iter())
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Output1 = usize;
+    type Output2 = u128;
+    type Error = Day16Error;
+
+    fn parse<R: BufRead>(mut input: R) -> Result<Self::Input, Day16Error> {
+        // The input has only one line
+        let mut hex_string = String::new();
+        input.read_line(&mut hex_string)?;
+
+        let bits = Bits::from_hex(0, hex_string.trim_end())?;
+
+        Packet::read(&mut bits.reader())
+    }
+
+    fn part1(packet: &Self::Input) -> Result<usize, Day16Error> {
+        Ok(packet.get_version_sum())
+    }
+
+    fn part2(packet: &Self::Input) -> Result<u128, Day16Error> {
+        packet.get_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let examples = [
+            ("8A004A801A8002F478", 16),
+            ("620080001611562C8802118E34", 12),
+            ("C0015000016115A2E0802F182340", 23),
+            ("A0016C880162017C3686B18A3D4780", 31),
+        ];
+
+        for (hex, version_sum) in examples {
+            let packet = Day16::parse(hex.as_bytes()).unwrap();
+
+            assert_eq!(Day16::part1(&packet).unwrap(), version_sum, "{}", hex);
+        }
+    }
+
+    #[test]
+    fn example_part2() {
+        let examples = [
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C005AC2F8F0", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ];
+
+        for (hex, result) in examples {
+            let packet = Day16::parse(hex.as_bytes()).unwrap();
+
+            assert_eq!(Day16::part2(&packet).unwrap(), result, "{}", hex);
+        }
+    }
+
+    #[test]
+    fn encode() {
+        let packet = Packet::literal(6, 2021);
+        assert_eq!(
+            packet.to_binary(LengthType::Bits).unwrap(),
+            "110100101111111000101"
+        );
+        assert_eq!(packet.to_hex(LengthType::Count).unwrap(), "D2FE28");
+
+        let packet = Packet::operator(
+            1,
+            Operation::LesserThan,
+            vec![Packet::literal(6, 10), Packet::literal(2, 20)],
+        );
+        assert_eq!(packet.to_hex(LengthType::Bits).unwrap(), "38006F45291200");
+
+        let packet = Packet::operator(
+            7,
+            Operation::Maximum,
+            vec![
+                Packet::literal(2, 1),
+                Packet::literal(4, 2),
+                Packet::literal(1, 3),
+            ],
+        );
+        assert_eq!(packet.to_hex(LengthType::Count).unwrap(), "EE00D40C823060");
+
+        let error = Packet::literal(8, 0).to_hex(LengthType::Bits).unwrap_err();
+        assert_eq!(error.to_string(), "packet version 8 doesn't fit in 3 bits");
+
+        let packet = Packet::operator(0, Operation::Sum, vec![Packet::literal(0, 0); 2048]);
+        let error = packet.to_hex(LengthType::Count).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "sub-packets count 2048 doesn't fit in 11 bits"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let examples = [
+            "8A004A801A8002F478",
+            "620080001611562C8802118E34",
+            "C0015000016115A2E0802F182340",
+            "A0016C880162017C3686B18A3D4780",
+            "9C0141080250320F1802104A08",
+        ];
+
+        for hex in examples {
+            let packet = Day16::parse(hex.as_bytes()).unwrap();
+
+            for length_type in [LengthType::Bits, LengthType::Count] {
+                let encoded = packet.to_hex(length_type).unwrap();
+                assert_eq!(Day16::parse(encoded.as_bytes()).unwrap(), packet, "{}", hex);
+            }
+        }
+    }
+
+    #[test]
+    fn render() {
+        let packet = Packet::operator(
+            1,
+            Operation::Sum,
+            vec![
+                Packet::operator(
+                    2,
+                    Operation::Product,
+                    vec![Packet::literal(3, 6), Packet::literal(4, 9)],
+                ),
+                Packet::operator(
+                    5,
+                    Operation::Minimum,
+                    vec![
+                        Packet::literal(6, 7),
+                        Packet::literal(7, 8),
+                        Packet::literal(0, 9),
+                    ],
+                ),
+            ],
+        );
+        assert_eq!(packet.to_string(), "(sum (product 6 9) (min 7 8 9))");
+
+        let encoded = packet.to_hex(LengthType::Count).unwrap();
+        let decoded = Day16::parse(encoded.as_bytes()).unwrap();
+        assert_eq!(
+            decoded.tree().to_string(),
+            "\
+sum = 61  [version 1, bit 0]
+  product = 54  [version 2, bit 18]
+    6  [version 3, bit 36]
+    9  [version 4, bit 47]
+  min = 7  [version 5, bit 58]
+    7  [version 6, bit 76]
+    8  [version 7, bit 87]
+    9  [version 0, bit 98]
+"
+        );
+
+        let packet = Day16::parse("D6004438".as_bytes()).unwrap();
+        assert_eq!(
+            packet.tree().to_string(),
+            "\
+gt failed: gt operation is only valid between two packets, but got 1  [version 6, bit 0]
+  7  [version 0, bit 18]
+"
+        );
+    }
+
+    #[test]
+    fn stream() {
+        // The first two examples of part 2 back to back, then zeros up to a whole byte
+        let packets = decode_stream(0, "C200B40A8204005AC33890").unwrap();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].to_string(), "(sum 1 2)");
+        assert_eq!(packets[1].offset(), Some(40));
+        assert_eq!(packets[1].to_string(), "(product 6 9)");
+
+        assert!(decode_stream(0, "").unwrap().is_empty());
+        assert!(decode_stream(0, "0000").unwrap().is_empty());
+
+        let error = decode_stream(0, "D2FE29").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bit 21: padding after the last packet isn't all zeros"
+        );
+
+        let error = decode_stream(2, "D2FE2X").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 6: invalid hexadecimal digit 'X'"
+        );
+    }
+
+    #[test]
+    fn large_values() {
+        // Literals and results past 64 bits
+        let big = Packet::literal(0, 1 << 100);
+        let packet = Packet::operator(
+            0,
+            Operation::Product,
+            vec![big.clone(), Packet::literal(0, 3)],
+        );
+        let encoded = packet.to_hex(LengthType::Count).unwrap();
+        let decoded = Day16::parse(encoded.as_bytes()).unwrap();
+        assert_eq!(Day16::part2(&decoded).unwrap(), 3 << 100);
+
+        let packet = Packet::operator(0, Operation::Product, vec![big.clone(), big]);
+        let encoded = packet.to_hex(LengthType::Bits).unwrap();
+        let decoded = Day16::parse(encoded.as_bytes()).unwrap();
+        assert_eq!(
+            Day16::part2(&decoded).unwrap_err().to_string(),
+            "product operation overflows 128 bits"
+        );
+
+        let packet = Packet::operator(0, Operation::Sum, vec![Packet::literal(0, u128::MAX); 2]);
+        assert!(matches!(
+            packet.get_result(),
+            Err(Day16Error::ResultOverflow {
+                operation: Operation::Sum
+            })
+        ));
+
+        // A literal of 33 groups of 1, one group more than fits in 128 bits
+        let error =
+            Day16::parse("12318C6318C6318C6318C6318C6318C6318C6318C420".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bit 0: literal value doesn't fit in 128 bits"
+        );
+    }
+
+    #[test]
+    fn nesting() {
+        // Sums of a single sub-packet nested `depth` times around a literal
+        let nested = |depth: usize| {
+            let mut bits = Bits::default();
+            for _ in 0..depth {
+                for (value, len) in [(0, 3), (0, 3), (1, 1), (1, 11)] {
+                    bits.write(value, len, "field").unwrap();
+                }
+            }
+            bits.extend(&Packet::literal(0, 7).to_bits(LengthType::Count).unwrap());
+            bits.to_hex()
+        };
+
+        let packet = Day16::parse(nested(MAX_DEPTH).as_bytes()).unwrap();
+        assert_eq!(Day16::part2(&packet).unwrap(), 7);
+        assert_eq!(
+            packet.to_string(),
+            format!("{}7{}", "(sum ".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH))
+        );
+        assert_eq!(packet.tree().to_string().lines().count(), MAX_DEPTH + 1);
+
+        for depth in [MAX_DEPTH + 1, 100_000] {
+            let error = Day16::parse(nested(depth).as_bytes()).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!(
+                    "bit {}: packets nested more than {} deep",
+                    18 * (MAX_DEPTH + 1),
+                    MAX_DEPTH
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day16::parse("8A004G801A8002F478".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: invalid hexadecimal digit 'G'"
+        );
+
+        // Literal packet cut in the middle of its value
+        let error = Day16::parse("D2FE".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bit 16: the transmission ends in the middle of the literal value"
+        );
+
+        // Sum packet of two sub-packets, with only one of them before the padding
+        let error = Day16::parse("02008408".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bit 32: the transmission ends in the middle of the packet type ID"
+        );
+
+        // Sub-packets length one bit shorter than the literal in it
+        let error = Day16::parse("0000284080".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bit 22: sub-packets take 11 bits, but the length field says 10"
+        );
+
+        // Greater than packet with a single sub-packet
+        let packet = Day16::parse("D6004438".as_bytes()).unwrap();
+        assert_eq!(
+            Day16::part2(&packet).unwrap_err().to_string(),
+            "gt operation is only valid between two packets, but got 1"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(16);
+        let packet = Day16::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day16::part1(&packet).unwrap(), 979);
+        assert_eq!(Day16::part2(&packet).unwrap(), 277110354175);
+
+        let encoded = packet.to_hex(LengthType::Bits).unwrap();
+        assert_eq!(Day16::parse(encoded.as_bytes()).unwrap(), packet);
+    }
+}
@@ -0,0 +1,218 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use regex::Regex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day17Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: expected 'target area: x=<x1>..<x2>, y=<y1>..<y2>', got '{line}'")]
+    InvalidTarget { position: Position, line: String },
+    #[error("{position}: invalid coordinate '{token}'")]
+    InvalidCoordinate { position: Position, token: String },
+    #[error("target area x={}..{}, y={}..{} must be to the right of and below the launch point", x.0, x.1, y.0, y.1)]
+    UnsupportedTarget {
+        x: (isize, isize),
+        y: (isize, isize),
+    },
+}
+
+type TargetArea = ((isize, isize), (isize, isize));
+
+// Calculates the mininum x velocity needed to reach the target
+fn get_min_x_velocity(target_x1: isize) -> isize {
+    let mut pos_x = 0;
+    let mut min_vx = 0;
+
+    while pos_x < target_x1 {
+        min_vx += 1;
+        pos_x += min_vx;
+    }
+
+    min_vx
+}
+
+// Calculates the maximum y velocity that will hit the target and the highest point possible
+fn get_max_y_velocity_and_peak(target_y: (isize, isize)) -> (isize, isize) {
+    let mut max_vy = 0;
+    let mut highest_peak = 0;
+
+    for vy_it in 0..=target_y.0.abs() {
+        let mut pos_y = 0;
+        let mut curr_highest_peak = 0;
+
+        let mut hit_target = false;
+        let mut overshot_target = false;
+
+        let mut vy = vy_it;
+        while !hit_target && !overshot_target {
+            pos_y += vy;
+            vy -= 1;
+
+            if pos_y > curr_highest_peak {
+                curr_highest_peak = pos_y;
+            }
+
+            hit_target = pos_y >= target_y.0 && pos_y <= target_y.1;
+            overshot_target = pos_y < target_y.0;
+        }
+
+        if hit_target {
+            max_vy = vy_it;
+            highest_peak = curr_highest_peak;
+        }
+    }
+
+    (max_vy, highest_peak)
+}
+
+fn get_all_possible_velocities(target: TargetArea) -> Vec<(isize, isize)> {
+    let (target_x, target_y) = target;
+    let min_vx = get_min_x_velocity(target_x.0);
+
+    let mut hit_velocities = Vec::new();
+    for xv_it in min_vx..=target_x.1 {
+        for yv_it in target_y.0..=target_y.0.abs() {
+            let mut pos_x = 0;
+            let mut pos_y = 0;
+
+            let mut hit_target = false;
+            let mut overshot_target = false;
+
+            let mut xv = xv_it;
+            let mut yv = yv_it;
+            while !hit_target && !overshot_target {
+                pos_x += xv;
+                pos_y += yv;
+
+                if xv.signum() != 0 {
+                    xv = (xv.abs() - 1) * xv.signum();
+                }
+                yv -= 1;
+
+                hit_target = (pos_x >= target_x.0 && pos_x <= target_x.1)
+                    && (pos_y >= target_y.0 && pos_y <= target_y.1);
+                overshot_target = pos_y < target_y.0 || pos_x > target_x.1;
+            }
+
+            if hit_target {
+                hit_velocities.push((xv_it, yv_it))
+            }
+        }
+    }
+
+    hit_velocities
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = TargetArea;
+    type Output1 = isize;
+    type Output2 = usize;
+    type Error = Day17Error;
+
+    fn parse<R: BufRead>(mut input: R) -> Result<Self::Input, Day17Error> {
+        // Regular expression used to match against the input
+        let re = Regex::new(r"^.*x=([-]?\d+)\.\.([-]?\d+).*y=([-]?\d+)\.\.([-]?\d+).*$").unwrap();
+
+        // The input has only one line
+        let mut target_str = String::new();
+        input.read_line(&mut target_str)?;
+        let target_str = target_str.trim_end();
+
+        // Get captures
+        let caps = match re.captures(target_str) {
+            Some(caps) => caps,
+            None => {
+                return Err(Day17Error::InvalidTarget {
+                    position: Position::line_start(0),
+                    line: target_str.to_string(),
+                })
+            }
+        };
+
+        // Extract ranges from captures, the regex guarantees all four are there
+        let mut coordinates = [0; 4];
+        for (coordinate, cap) in coordinates.iter_mut().zip(caps.iter().skip(1).flatten()) {
+            *coordinate = error::parse_token(0, target_str, cap.as_str(), |position, token| {
+                Day17Error::InvalidCoordinate { position, token }
+            })?;
+        }
+        let [x1, x2, y1, y2] = coordinates;
+
+        // The probe is launched from the origin, the target must be ahead of it and below it
+        if !(0 < x1 && x1 <= x2 && y1 <= y2 && y2 < 0) {
+            return Err(Day17Error::UnsupportedTarget {
+                x: (x1, x2),
+                y: (y1, y2),
+            });
+        }
+
+        Ok(((x1, x2), (y1, y2)))
+    }
+
+    fn part1(target: &Self::Input) -> Result<isize, Day17Error> {
+        let (_target_x, target_y) = *target;
+        let (_max_vy, peak) = get_max_y_velocity_and_peak(target_y);
+
+        Ok(peak)
+    }
+
+    fn part2(target: &Self::Input) -> Result<usize, Day17Error> {
+        let hit_velocities = get_all_possible_velocities(*target);
+
+        Ok(hit_velocities.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+target area: x=20..30, y=-10..-5
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day17::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day17::part1(&input).unwrap(), 45);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day17::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day17::part2(&input).unwrap(), 112);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day17::parse("target area: x=20..30".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected 'target area: x=<x1>..<x2>, y=<y1>..<y2>', got 'target area: x=20..30'"
+        );
+
+        let error = Day17::parse("target area: x=20..30, y=5..10".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "target area x=20..30, y=5..10 must be to the right of and below the launch point"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(17);
+        let input = Day17::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day17::part1(&input).unwrap(), 5565);
+        assert_eq!(Day17::part2(&input).unwrap(), 2118);
+    }
+}
@@ -0,0 +1,683 @@
+use std::cmp::Reverse;
+use std::fmt::{self, Display};
+use std::io;
+use std::io::BufRead;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::error::Position;
+use common::Solution;
+use itertools::Itertools;
+use rayon::prelude::*;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day18Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: invalid character '{token}'")]
+    InvalidCharacter { position: Position, token: char },
+    #[error("{position}: unbalanced brackets")]
+    UnbalancedBrackets { position: Position },
+    #[error("{position}: unexpected '{token}', expected {expected}")]
+    UnexpectedCharacter {
+        position: Position,
+        token: char,
+        expected: &'static str,
+    },
+    #[error("{position}: expected {expected}")]
+    Incomplete {
+        position: Position,
+        expected: &'static str,
+    },
+    #[error("{position}: pair nested in more than {max} pairs")]
+    TooDeep { position: Position, max: usize },
+    #[error("{position}: number '{token}' is larger than {max}")]
+    NumberTooLarge {
+        position: Position,
+        token: String,
+        max: usize,
+    },
+    #[error("at least {needed} snailfish numbers are needed, got {found}")]
+    NotEnoughNumbers { needed: usize, found: usize },
+}
+
+// Pairs a number can be nested in, as reduced numbers never go deeper
+const MAX_DEPTH: usize = 4;
+
+// Largest regular number accepted in the input. Splitting a number down takes time in proportion
+// to its value, and reduced numbers never go past 9 anyway
+const MAX_REGULAR: usize = 999;
+
+#[derive(Debug, Clone)]
+struct SnailFishPart {
+    value: usize,
+    depth: usize,
+}
+
+impl SnailFishPart {
+    fn new(value: usize, depth: usize) -> Self {
+        Self { value, depth }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SnailfishNumber {
+    parts: Vec<SnailFishPart>,
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = Day18Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_line(0, s)
+    }
+}
+
+impl AddAssign for SnailfishNumber {
+    fn add_assign(&mut self, rhs: Self) {
+        self.join(rhs);
+        self.reduce();
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut new_num = self;
+        new_num += rhs;
+        new_num
+    }
+}
+
+impl Sum for SnailfishNumber {
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        let first_num = iter.next().unwrap();
+        iter.fold(first_num, |acc, sfn| acc + sfn)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Addition,
+    Explode { left: usize, right: usize },
+    Split { value: usize },
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Addition => write!(f, "after addition"),
+            Self::Explode { left, right } => write!(f, "after explode [{},{}]", left, right),
+            Self::Split { value } => write!(f, "after split {}", value),
+        }
+    }
+}
+
+// A step of the reduction of a sum, and the number it leaves
+#[derive(Debug, Clone)]
+pub struct ReductionStep {
+    pub action: Action,
+    pub number: SnailfishNumber,
+}
+
+impl Display for ReductionStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<20} {}", format!("{}:", self.action), self.number)
+    }
+}
+
+// A snailfish number as a tree, which is how it's written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Element {
+    Regular(usize),
+    Pair(Box<Element>, Box<Element>),
+}
+
+impl Element {
+    fn flatten(&self, depth: usize, parts: &mut Vec<SnailFishPart>) {
+        match self {
+            Self::Regular(value) => parts.push(SnailFishPart::new(*value, depth)),
+            Self::Pair(left, right) => {
+                left.flatten(depth + 1, parts);
+                right.flatten(depth + 1, parts);
+            }
+        }
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Regular(value) => write!(f, "{}", value),
+            Self::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+// Recursive descent parser over a single line
+struct Parser {
+    index: usize,
+    chars: Vec<char>,
+    next: usize,
+}
+
+impl Parser {
+    fn new(index: usize, line: &str) -> Self {
+        Parser {
+            index,
+            chars: line.chars().collect(),
+            next: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.next).copied()
+    }
+
+    fn position(&self) -> Position {
+        Position::new(self.index + 1, self.next + 1)
+    }
+
+    // Fails on the next character, or on the end of the line with `depth` pairs still open
+    fn unexpected(&self, depth: usize, expected: &'static str) -> Day18Error {
+        let position = self.position();
+        match self.peek() {
+            Some(token) if !"[],0123456789".contains(token) => {
+                Day18Error::InvalidCharacter { position, token }
+            }
+            Some(token) => Day18Error::UnexpectedCharacter {
+                position,
+                token,
+                expected,
+            },
+            None if depth > 0 => Day18Error::UnbalancedBrackets { position },
+            None => Day18Error::Incomplete { position, expected },
+        }
+    }
+
+    fn expect(&mut self, c: char, depth: usize, expected: &'static str) -> Result<(), Day18Error> {
+        if self.peek() != Some(c) {
+            return Err(self.unexpected(depth, expected));
+        }
+        self.next += 1;
+
+        Ok(())
+    }
+
+    // Parses the element starting at the next character, inside `depth` pairs
+    fn element(&mut self, depth: usize) -> Result<Element, Day18Error> {
+        match self.peek() {
+            Some('[') if depth >= MAX_DEPTH => Err(Day18Error::TooDeep {
+                position: self.position(),
+                max: MAX_DEPTH,
+            }),
+            Some('[') => {
+                self.next += 1;
+                let left = self.element(depth + 1)?;
+                self.expect(',', depth + 1, "','")?;
+                let right = self.element(depth + 1)?;
+                self.expect(']', depth + 1, "']'")?;
+
+                Ok(Element::Pair(Box::new(left), Box::new(right)))
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let position = self.position();
+                let start = self.next;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.next += 1;
+                }
+
+                let token: String = self.chars[start..self.next].iter().collect();
+                match token.parse() {
+                    Ok(value) if value <= MAX_REGULAR => Ok(Element::Regular(value)),
+                    _ => Err(Day18Error::NumberTooLarge {
+                        position,
+                        token,
+                        max: MAX_REGULAR,
+                    }),
+                }
+            }
+            _ => Err(self.unexpected(depth, "a number or '['")),
+        }
+    }
+
+    // A whole line, which always holds a single pair
+    fn number(&mut self) -> Result<Element, Day18Error> {
+        if self.peek() != Some('[') {
+            return Err(self.unexpected(0, "'['"));
+        }
+        let element = self.element(0)?;
+
+        match self.peek() {
+            None => Ok(element),
+            Some(']') => Err(Day18Error::UnbalancedBrackets {
+                position: self.position(),
+            }),
+            Some(_) => Err(self.unexpected(0, "the end of the line")),
+        }
+    }
+}
+
+impl From<&Element> for SnailfishNumber {
+    fn from(element: &Element) -> Self {
+        let mut parts = Vec::new();
+        element.flatten(0, &mut parts);
+
+        Self { parts }
+    }
+}
+
+impl Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_tree())
+    }
+}
+
+impl SnailfishNumber {
+    // Parses the line with 0-based index `index`, so errors point at the right place
+    fn parse_line(index: usize, s: &str) -> Result<Self, Day18Error> {
+        let element = Parser::new(index, s).number()?;
+
+        Ok(Self::from(&element))
+    }
+
+    // Rebuilds the pairs from the depths of the regular numbers, two neighbours at the same depth
+    // always being the two halves of a pair
+    pub fn to_tree(&self) -> Element {
+        let mut stack: Vec<(Element, usize)> = Vec::new();
+        for part in &self.parts {
+            stack.push((Element::Regular(part.value), part.depth));
+
+            while let [.., (_, left_depth), (_, right_depth)] = stack[..] {
+                if left_depth != right_depth {
+                    break;
+                }
+                let (right, depth) = stack.pop().unwrap();
+                let (left, _) = stack.pop().unwrap();
+                stack.push((Element::Pair(Box::new(left), Box::new(right)), depth - 1));
+            }
+        }
+
+        stack
+            .pop()
+            .map_or(Element::Regular(0), |(element, _)| element)
+    }
+
+    // Pairs the two numbers up, without reducing the result
+    fn join(&mut self, rhs: Self) {
+        self.parts.extend(rhs.parts);
+        for part in self.parts.iter_mut() {
+            part.depth += 1;
+        }
+    }
+
+    fn reduce(&mut self) {
+        while self.reduce_step().is_some() {}
+    }
+
+    fn reduce_step(&mut self) -> Option<Action> {
+        self.explode().or_else(|| self.split())
+    }
+
+    // Adds the numbers, recording the number after the addition and after every reduction step
+    pub fn add_traced(mut self, rhs: Self) -> (Self, Vec<ReductionStep>) {
+        self.join(rhs);
+        let mut steps = vec![ReductionStep {
+            action: Action::Addition,
+            number: self.clone(),
+        }];
+        while let Some(action) = self.reduce_step() {
+            steps.push(ReductionStep {
+                action,
+                number: self.clone(),
+            });
+        }
+
+        (self, steps)
+    }
+
+    fn explode(&mut self) -> Option<Action> {
+        for (
+            i,
+            (
+                &SnailFishPart {
+                    value: value1,
+                    depth: depth1,
+                },
+                &SnailFishPart {
+                    value: value2,
+                    depth: depth2,
+                },
+            ),
+        ) in self.parts.iter().tuple_windows().enumerate()
+        {
+            if depth1 == MAX_DEPTH + 1 && depth2 == MAX_DEPTH + 1 {
+                if self.parts.get(i.saturating_sub(1)).is_some() && i.saturating_sub(1) != i {
+                    self.parts.get_mut(i - 1).unwrap().value += value1;
+                }
+
+                if self.parts.get(i + 2).is_some() {
+                    self.parts.get_mut(i + 2).unwrap().value += value2;
+                }
+
+                self.parts.drain(i..i + 2);
+                self.parts.insert(i, SnailFishPart::new(0, 4));
+                return Some(Action::Explode {
+                    left: value1,
+                    right: value2,
+                });
+            }
+        }
+
+        None
+    }
+
+    fn split(&mut self) -> Option<Action> {
+        for (i, part) in self.parts.iter().enumerate() {
+            if part.value > 9 {
+                let (value, depth) = (part.value, part.depth);
+                self.parts.remove(i);
+                self.parts
+                    .insert(i, SnailFishPart::new(value / 2, depth + 1));
+                self.parts
+                    .insert(i + 1, SnailFishPart::new(value.div_ceil(2), depth + 1));
+
+                return Some(Action::Split { value });
+            }
+        }
+
+        None
+    }
+
+    fn value_sum(&self) -> usize {
+        self.parts
+            .iter()
+            .fold(0, |sum: usize, part| sum.saturating_add(part.value))
+    }
+
+    // Explodes only ever drop values and splits keep their sum, so a sum of the two numbers can't
+    // have a larger magnitude than the heaviest regular numbers filled with 9s while their values last
+    fn magnitude_bound(&self, rhs: &Self) -> usize {
+        let mut values = self.value_sum().saturating_add(rhs.value_sum());
+        let mut bound: usize = 0;
+        for weight in LEAF_WEIGHTS {
+            let value = values.min(9);
+            bound = bound.saturating_add(value * weight);
+            values -= value;
+        }
+
+        bound
+    }
+
+    pub fn magnitude(&self) -> usize {
+        let mut mag = self.clone();
+        for depth in (1..=MAX_DEPTH).rev() {
+            while mag.magnitude_rec(depth) {}
+        }
+
+        mag.parts[0].value
+    }
+
+    fn magnitude_rec(&mut self, depth: usize) -> bool {
+        for (i, (part1, part2)) in self.parts.iter().tuple_windows().enumerate() {
+            if part1.depth == depth && part2.depth == depth {
+                self.parts[i] = SnailFishPart::new(3 * part1.value + 2 * part2.value, depth - 1);
+                self.parts.remove(i + 1);
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+// Weights in the magnitude of the 16 regular numbers of a number nested 4 deep, heaviest first
+const LEAF_WEIGHTS: [usize; 16] = [
+    81, 54, 54, 54, 54, 36, 36, 36, 36, 36, 36, 24, 24, 24, 24, 16,
+];
+
+// Largest magnitude of a sum of two different numbers, and the indices of the numbers adding up to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LargestSum {
+    pub magnitude: usize,
+    pub left: usize,
+    pub right: usize,
+}
+
+// Tries every ordered pair in parallel, with `prune` skipping the pairs whose magnitude bound is already
+// beaten. Ties go to the pair that comes first, whether pruning or not
+pub fn largest_sum(numbers: &[SnailfishNumber], prune: bool) -> Result<LargestSum, Day18Error> {
+    // Pairs with the largest bounds go first, so that the best magnitude grows as early as possible
+    let mut pairs: Vec<(usize, usize, usize)> = (0..numbers.len())
+        .flat_map(|left| (0..numbers.len()).map(move |right| (left, right)))
+        .filter(|(left, right)| left != right)
+        .map(|(left, right)| {
+            let bound = numbers[left].magnitude_bound(&numbers[right]);
+            (bound, left, right)
+        })
+        .collect();
+    pairs.sort_by_key(|&(bound, left, right)| (Reverse(bound), left, right));
+
+    let best = AtomicUsize::new(0);
+    pairs
+        .par_iter()
+        .filter_map(|&(bound, left, right)| {
+            if prune && bound < best.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            let magnitude = (numbers[left].clone() + numbers[right].clone()).magnitude();
+            best.fetch_max(magnitude, Ordering::Relaxed);
+
+            Some(LargestSum {
+                magnitude,
+                left,
+                right,
+            })
+        })
+        .max_by_key(|sum| (sum.magnitude, Reverse((sum.left, sum.right))))
+        .ok_or(Day18Error::NotEnoughNumbers {
+            needed: 2,
+            found: numbers.len(),
+        })
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<SnailfishNumber>;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day18Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day18Error> {
+        let mut numbers = Vec::new();
+
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+
+            let number = SnailfishNumber::parse_line(index, &line)?;
+            numbers.push(number);
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<usize, Day18Error> {
+        if numbers.is_empty() {
+            return Err(Day18Error::NotEnoughNumbers {
+                needed: 1,
+                found: 0,
+            });
+        }
+
+        let snailfish_sum: SnailfishNumber = numbers.iter().cloned().sum();
+
+        Ok(snailfish_sum.magnitude())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<usize, Day18Error> {
+        Ok(largest_sum(numbers, true)?.magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day18::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day18::part1(&input).unwrap(), 4140);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day18::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day18::part2(&input).unwrap(), 3993);
+    }
+
+    #[test]
+    fn largest_pair() {
+        let input = Day18::parse(EXAMPLE.as_bytes()).unwrap();
+        let expected = LargestSum {
+            magnitude: 3993,
+            left: 8,
+            right: 0,
+        };
+
+        assert_eq!(largest_sum(&input, false).unwrap(), expected);
+        assert_eq!(largest_sum(&input, true).unwrap(), expected);
+
+        for (left, right) in (0..input.len()).tuple_combinations() {
+            let magnitude = (input[left].clone() + input[right].clone()).magnitude();
+            assert!(magnitude <= input[left].magnitude_bound(&input[right]));
+        }
+
+        // Values too large to add up only make the bound as loose as it gets
+        let huge = SnailfishNumber::from(&Element::Pair(
+            Box::new(Element::Regular(usize::MAX)),
+            Box::new(Element::Regular(1)),
+        ));
+        let loosest = LEAF_WEIGHTS.iter().sum::<usize>() * 9;
+        assert_eq!(huge.magnitude_bound(&huge), loosest);
+    }
+
+    #[test]
+    fn display() {
+        for line in EXAMPLE.lines() {
+            let number: SnailfishNumber = line.parse().unwrap();
+            assert_eq!(number.to_string(), line);
+        }
+
+        let number: SnailfishNumber = "[10,[3,123]]".parse().unwrap();
+        assert_eq!(number.to_string(), "[10,[3,123]]");
+
+        // The reduction example from the puzzle text
+        let number: SnailfishNumber = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+        assert_eq!(
+            (number + "[1,1]".parse().unwrap()).to_string(),
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        );
+    }
+
+    #[test]
+    fn trace() {
+        let number: SnailfishNumber = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+        let (sum, steps) = number.add_traced("[1,1]".parse().unwrap());
+
+        let expected = "\
+after addition:      [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]
+after explode [4,3]: [[[[0,7],4],[7,[[8,4],9]]],[1,1]]
+after explode [8,4]: [[[[0,7],4],[15,[0,13]]],[1,1]]
+after split 15:      [[[[0,7],4],[[7,8],[0,13]]],[1,1]]
+after split 13:      [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]
+after explode [6,7]: [[[[0,7],4],[[7,8],[6,0]]],[8,1]]
+";
+        let trace: String = steps.iter().map(|step| format!("{}\n", step)).collect();
+        assert_eq!(trace, expected);
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day18::parse("[1,2]\n[[3,4],5\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 9: unbalanced brackets");
+
+        let error = Day18::parse("[1,2]\n[3;4]\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: invalid character ';'");
+
+        let malformed = [
+            ("[1,2,3]", "line 1, column 5: unexpected ',', expected ']'"),
+            ("[1]", "line 1, column 3: unexpected ']', expected ','"),
+            (
+                "[,2]",
+                "line 1, column 2: unexpected ',', expected a number or '['",
+            ),
+            ("[1,2]]", "line 1, column 6: unbalanced brackets"),
+            (
+                "[1,2][3,4]",
+                "line 1, column 6: unexpected '[', expected the end of the line",
+            ),
+            ("7", "line 1, column 1: unexpected '7', expected '['"),
+            ("", "line 1, column 1: expected '['"),
+            (
+                "[[[[[1,2],3],4],5],6]",
+                "line 1, column 5: pair nested in more than 4 pairs",
+            ),
+        ];
+        for (line, message) in malformed {
+            let error = line.parse::<SnailfishNumber>().unwrap_err();
+            assert_eq!(error.to_string(), message, "{}", line);
+        }
+
+        let error = "[1,99999999999999999999]"
+            .parse::<SnailfishNumber>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: number '99999999999999999999' is larger than 999"
+        );
+
+        let error = "[[1,1000],999]".parse::<SnailfishNumber>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: number '1000' is larger than 999"
+        );
+        assert!("[[1,999],0999]".parse::<SnailfishNumber>().is_ok());
+
+        let input = Day18::parse("[1,2]\n".as_bytes()).unwrap();
+        assert_eq!(
+            Day18::part2(&input).unwrap_err().to_string(),
+            "at least 2 snailfish numbers are needed, got 1"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(18);
+        let input = Day18::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day18::part1(&input).unwrap(), 3869);
+        assert_eq!(Day18::part2(&input).unwrap(), 4671);
+    }
+}
@@ -0,0 +1,464 @@
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use fxhash::FxHashSet;
+use itertools::Itertools;
+use nalgebra::{Matrix3, Vector3};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day19Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: expected 'x,y,z', got '{line}'")]
+    InvalidBeacon { position: Position, line: String },
+    #[error("{position}: invalid coordinate '{token}'")]
+    InvalidCoordinate { position: Position, token: String },
+    #[error("scanners {scanners:?} don't overlap with any other scanner")]
+    Unaligned { scanners: Vec<usize> },
+}
+
+// The 24 rotations a scanner can be in, the identity first
+pub static CHANGE_OF_BASIS_MATRIXES: [Matrix3<isize>; 24] = [
+    Matrix3::new(1, 0, 0, 0, 1, 0, 0, 0, 1),
+    Matrix3::new(1, 0, 0, 0, -1, 0, 0, 0, -1),
+    Matrix3::new(1, 0, 0, 0, 0, -1, 0, 1, 0),
+    Matrix3::new(1, 0, 0, 0, 0, 1, 0, -1, 0),
+    Matrix3::new(-1, 0, 0, 0, -1, 0, 0, 0, 1),
+    Matrix3::new(-1, 0, 0, 0, 1, 0, 0, 0, -1),
+    Matrix3::new(-1, 0, 0, 0, 0, 1, 0, 1, 0),
+    Matrix3::new(-1, 0, 0, 0, 0, -1, 0, -1, 0),
+    Matrix3::new(0, -1, 0, 1, 0, 0, 0, 0, 1),
+    Matrix3::new(0, 1, 0, 1, 0, 0, 0, 0, -1),
+    Matrix3::new(0, 0, 1, 1, 0, 0, 0, 1, 0),
+    Matrix3::new(0, 0, -1, 1, 0, 0, 0, -1, 0),
+    Matrix3::new(0, 1, 0, -1, 0, 0, 0, 0, 1),
+    Matrix3::new(0, -1, 0, -1, 0, 0, 0, 0, -1),
+    Matrix3::new(0, 0, -1, -1, 0, 0, 0, 1, 0),
+    Matrix3::new(0, 0, 1, -1, 0, 0, 0, -1, 0),
+    Matrix3::new(0, 0, -1, 0, 1, 0, 1, 0, 0),
+    Matrix3::new(0, 0, 1, 0, -1, 0, 1, 0, 0),
+    Matrix3::new(0, 1, 0, 0, 0, 1, 1, 0, 0),
+    Matrix3::new(0, -1, 0, 0, 0, -1, 1, 0, 0),
+    Matrix3::new(0, 0, 1, 0, 1, 0, -1, 0, 0),
+    Matrix3::new(0, 0, -1, 0, -1, 0, -1, 0, 0),
+    Matrix3::new(0, 1, 0, 0, 0, -1, -1, 0, 0),
+    Matrix3::new(0, -1, 0, 0, 0, 1, -1, 0, 0),
+];
+
+// Every beacon seen so far, relative to scanner 0
+pub type BeaconSet = FxHashSet<Vector3<isize>>;
+
+#[derive(Debug, Clone)]
+pub struct Scan {
+    beacons: Vec<Vector3<isize>>,
+}
+
+// How scans are matched against each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlignmentConfig {
+    // Beacons two scans must share to be aligned
+    pub min_overlap: usize,
+    // Distance along every axis up to which a scanner sees all the beacons around it
+    pub range: isize,
+}
+
+impl Default for AlignmentConfig {
+    fn default() -> Self {
+        AlignmentConfig {
+            min_overlap: 12,
+            range: 1000,
+        }
+    }
+}
+
+// Where a scanner is relative to scanner 0: a beacon at `b` relative to the scanner is at
+// `rotation_matrix() * b + position` relative to scanner 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pose {
+    pub position: Vector3<isize>,
+    // Index into `CHANGE_OF_BASIS_MATRIXES`
+    pub rotation: usize,
+}
+
+impl Pose {
+    pub fn rotation_matrix(&self) -> &'static Matrix3<isize> {
+        &CHANGE_OF_BASIS_MATRIXES[self.rotation]
+    }
+}
+
+// Every beacon relative to scanner 0, and the pose of every scanner in input order
+#[derive(Debug, Clone)]
+pub struct Alignment {
+    pub beacons: BeaconSet,
+    pub poses: Vec<Pose>,
+}
+
+fn try_update_scan(
+    complete_scan: &mut BeaconSet,
+    scan: &Scan,
+    config: &AlignmentConfig,
+) -> Option<Pose> {
+    for (rotation, base_transform_mtx) in CHANGE_OF_BASIS_MATRIXES.iter().enumerate() {
+        let beacons = &scan.beacons;
+
+        // Transform all the beacon scans into the new base
+        let transformed_beacons = beacons
+            .iter()
+            .map(|b| base_transform_mtx * b)
+            .collect::<Vec<_>>();
+
+        // Build iterator over the distance between all pairs of points
+        let distances_iter = complete_scan
+            .iter()
+            .cartesian_product(&transformed_beacons)
+            .map(|(orig, dest)| orig - dest);
+
+        for dist in distances_iter {
+            // Translate all beacons scans by this distance
+            let translated_beacons_iter = transformed_beacons.iter().map(|b| b + dist);
+
+            // Count overlapping beacons
+            let overlap_count = translated_beacons_iter
+                .clone()
+                .filter(|tv| complete_scan.contains(tv))
+                .count();
+
+            // If enough beacons overlap and the scanner saw every known beacon in its range,
+            // update the scan set
+            if overlap_count >= config.min_overlap {
+                let translated_beacons: BeaconSet = translated_beacons_iter.collect();
+                let sees_all = complete_scan
+                    .iter()
+                    .filter(|b| (*b - dist).abs().max() <= config.range)
+                    .all(|b| translated_beacons.contains(b));
+
+                if sees_all {
+                    complete_scan.extend(translated_beacons);
+                    return Some(Pose {
+                        position: dist,
+                        rotation,
+                    });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// Aligns every scan with scanner 0
+pub fn align_scans(scans: &[Scan], config: &AlignmentConfig) -> Result<Alignment, Day19Error> {
+    // Keep the index of every scanner around to report the ones that can't be aligned
+    let mut scans: Vec<(usize, &Scan)> = scans.iter().enumerate().collect();
+
+    // Build initial scan set from scanner 0
+    let mut complete_scan = scans
+        .remove(0)
+        .1
+        .beacons
+        .iter()
+        .copied()
+        .collect::<BeaconSet>();
+
+    // Scanner 0 sits at the origin, unrotated
+    let mut poses = vec![None; scans.len() + 1];
+    poses[0] = Some(Pose {
+        position: Vector3::zeros(),
+        rotation: 0,
+    });
+
+    while !scans.is_empty() {
+        let n_unaligned = scans.len();
+
+        for i in (0..scans.len()).rev() {
+            if let Some(pose) = try_update_scan(&mut complete_scan, scans[i].1, config) {
+                poses[scans[i].0] = Some(pose);
+                scans.swap_remove(i);
+            }
+        }
+
+        // Nothing else will overlap if a whole pass aligned nothing
+        if scans.len() == n_unaligned {
+            return Err(Day19Error::Unaligned {
+                scanners: scans.iter().map(|(i, _)| *i).sorted().collect(),
+            });
+        }
+    }
+
+    Ok(Alignment {
+        beacons: complete_scan,
+        poses: poses.into_iter().flatten().collect(),
+    })
+}
+
+// Reads every scan of the input, in order
+pub fn parse_scans<R: BufRead>(input: R) -> Result<Vec<Scan>, Day19Error> {
+    // Collect scans
+    let mut scans = Vec::new();
+    let mut curr_scan_vec = Vec::new();
+    for (index, line_result) in input.lines().enumerate() {
+        let line = line_result?;
+
+        // Skip empty lines
+        if line.is_empty() {
+            continue;
+        }
+
+        // When reading a new scan, save the previous one (if it has content)
+        if line.starts_with("---") {
+            if !curr_scan_vec.is_empty() {
+                scans.push(Scan {
+                    beacons: curr_scan_vec.clone(),
+                });
+                curr_scan_vec.clear();
+            }
+            continue;
+        }
+
+        // Read the 3D point
+        let fields: Vec<&str> = line.split(',').collect();
+        if fields.len() != 3 {
+            return Err(Day19Error::InvalidBeacon {
+                position: Position::line_start(index),
+                line,
+            });
+        }
+
+        let coordinates = fields
+            .iter()
+            .map(|v| {
+                error::parse_token(index, &line, v, |position, token| {
+                    Day19Error::InvalidCoordinate { position, token }
+                })
+            })
+            .collect::<Result<Vec<isize>, _>>()?;
+        curr_scan_vec.push(Vector3::from_vec(coordinates));
+    }
+    scans.push(Scan {
+        beacons: curr_scan_vec,
+    });
+
+    Ok(scans)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Alignment;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day19Error;
+
+    // Aligning is the bulk of the work and both parts need it, so it's done along with parsing
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day19Error> {
+        align_scans(&parse_scans(input)?, &AlignmentConfig::default())
+    }
+
+    fn part1(alignment: &Self::Input) -> Result<usize, Day19Error> {
+        Ok(alignment.beacons.len())
+    }
+
+    fn part2(alignment: &Self::Input) -> Result<usize, Day19Error> {
+        let max_distance = alignment
+            .poses
+            .iter()
+            .tuple_combinations()
+            .map(|(s1, s2)| (s1.position - s2.position).abs().sum())
+            .max()
+            .unwrap_or(0);
+
+        Ok(max_distance as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Small example built by hand: three scanners, two of them rotated, each overlapping scanner 0
+    const EXAMPLE: &str = "\
+--- scanner 0 ---
+-207,243,180
+309,-195,-43
+-227,873,-189
+312,-767,356
+686,-148,72
+737,-588,-610
+215,583,-88
+337,316,690
+-408,617,-134
+-576,-839,-797
+798,-912,853
+-147,-549,621
+440,-452,955
+319,52,690
+-925,771,-799
+-348,-41,-524
+646,-408,443
+-550,475,-388
+-947,-46,-229
+721,-437,633
+256,218,-220
+571,-205,748
+823,-995,-499
+215,-467,89
+-788,-74,-608
+119,891,176
+637,-753,293
+423,-954,411
+-252,-653,641
+-416,1000,28
+982,767,355
+-859,-598,282
+
+--- scanner 1 ---
+-844,307,607
+120,786,444
+-650,-506,-56
+384,768,444
+651,890,-334
+-927,282,-745
+835,123,109
+-369,384,387
+-80,419,-174
+-137,534,502
+24,-688,11
+-699,793,110
+-520,368,-856
+-127,796,-289
+286,849,-466
+-886,682,165
+-685,468,47
+-399,890,-157
+-384,665,709
+-755,-216,65
+-340,459,197
+959,986,-70
+
+--- scanner 2 ---
+168,-714,-644
+-462,-738,-915
+146,-144,173
+234,367,725
+543,-303,-348
+677,113,-873
+946,706,676
+745,-439,168
+841,726,780
+866,-104,-746
+305,-523,201
+685,-49,-490
+886,265,-116
+517,-712,966
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day19::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day19::part1(&input).unwrap(), 36);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day19::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day19::part2(&input).unwrap(), 2724);
+    }
+
+    #[test]
+    fn poses() {
+        let input = parse_scans(EXAMPLE.as_bytes()).unwrap();
+        let alignment = align_scans(&input, &AlignmentConfig::default()).unwrap();
+
+        assert_eq!(
+            alignment.poses,
+            [
+                Pose {
+                    position: Vector3::new(0, 0, 0),
+                    rotation: 0
+                },
+                Pose {
+                    position: Vector3::new(1105, -68, 246),
+                    rotation: 8
+                },
+                Pose {
+                    position: Vector3::new(-1093, 127, -85),
+                    rotation: 2
+                },
+            ]
+        );
+
+        // Every beacon a scanner saw lands on a known beacon once moved by the scanner's pose
+        for (scan, pose) in input.iter().zip(&alignment.poses) {
+            for beacon in &scan.beacons {
+                let moved = pose.rotation_matrix() * beacon + pose.position;
+                assert!(alignment.beacons.contains(&moved));
+            }
+        }
+    }
+
+    #[test]
+    fn alignment_config() {
+        let input = parse_scans(EXAMPLE.as_bytes()).unwrap();
+
+        // Scanners 1 and 2 share 19 and 13 beacons with scanner 0
+        let config = AlignmentConfig {
+            min_overlap: 20,
+            ..AlignmentConfig::default()
+        };
+        assert_eq!(
+            align_scans(&input, &config).unwrap_err().to_string(),
+            "scanners [1, 2] don't overlap with any other scanner"
+        );
+
+        // A wider range means scanner 0 should have seen beacons it didn't report
+        let config = AlignmentConfig {
+            range: 1500,
+            ..AlignmentConfig::default()
+        };
+        assert!(align_scans(&input, &config).is_err());
+
+        let config = AlignmentConfig {
+            min_overlap: 3,
+            range: 1000,
+        };
+        assert_eq!(align_scans(&input, &config).unwrap().beacons.len(), 36);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error =
+            Day19::parse("--- scanner 0 ---\n404,-588,-901\n528,-643\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected 'x,y,z', got '528,-643'"
+        );
+
+        let error = Day19::parse("--- scanner 0 ---\n404,-588,-9O1\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 10: invalid coordinate '-9O1'"
+        );
+
+        let error =
+            Day19::parse("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6\n".as_bytes())
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "scanners [1] don't overlap with any other scanner"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(19);
+        let input = Day19::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day19::part1(&input).unwrap(), 308);
+        assert_eq!(Day19::part2(&input).unwrap(), 12124);
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt::Display;
+use std::io;
+use std::io::BufRead;
+
+use common::error::Position;
+use common::grid::{Grid, ParseGridError};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day20Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("the input is empty")]
+    Empty,
+    #[error("{position}: invalid pixel '{token}', expected '#' or '.'")]
+    InvalidPixel { position: Position, token: char },
+    #[error("the enhancement algorithm must have 512 pixels, found {found}")]
+    InvalidAlgorithmLength { found: usize },
+    #[error("the image is empty")]
+    EmptyImage,
+    #[error(transparent)]
+    Grid(#[from] ParseGridError),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Pixel {
+    Light,
+    Dark,
+}
+
+impl TryFrom<char> for Pixel {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Pixel::Light),
+            '.' => Ok(Pixel::Dark),
+            _ => Err(c),
+        }
+    }
+}
+
+impl Display for Pixel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixel_str = match self {
+            Self::Light => '#',
+            Self::Dark => '.',
+        };
+
+        write!(f, "{}", pixel_str)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    pixels: Grid<Pixel>,
+    infinity_pixel: Pixel,
+}
+
+impl Image {
+    // `pixels` must not be empty
+    fn new(pixels: Grid<Pixel>) -> Self {
+        Self {
+            pixels,
+            infinity_pixel: Pixel::Dark,
+        }
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let padded_image = ImageEnhancer::pad(self, 4);
+
+        write!(f, "{}", padded_image.pixels)
+    }
+}
+
+impl Image {
+    fn get_light_pixels_count(&self) -> usize {
+        self.pixels
+            .iter()
+            .filter(|&&p| matches!(p, Pixel::Light))
+            .count()
+    }
+}
+
+#[derive(Debug)]
+pub struct ImageEnhancer {
+    algorithm: Vec<Pixel>,
+}
+
+impl ImageEnhancer {
+    // The algorithm is always on the first line
+    fn parse(line: &str) -> Result<Self, Day20Error> {
+        let algorithm = line
+            .chars()
+            .enumerate()
+            .map(|(column, c)| {
+                Pixel::try_from(c).map_err(|token| Day20Error::InvalidPixel {
+                    position: Position::new(1, column + 1),
+                    token,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if algorithm.len() != 512 {
+            return Err(Day20Error::InvalidAlgorithmLength {
+                found: algorithm.len(),
+            });
+        }
+
+        Ok(Self { algorithm })
+    }
+}
+
+impl ImageEnhancer {
+    // Pad the image with a layer of infinity pixels, `width` pixels wide
+    fn pad(image: &Image, width: usize) -> Image {
+        let pixels = Grid::from_fn(
+            image.pixels.width() + 2 * width,
+            image.pixels.height() + 2 * width,
+            |(row, col)| {
+                let pos = (row.wrapping_sub(width), col.wrapping_sub(width));
+                *image.pixels.get(pos).unwrap_or(&image.infinity_pixel)
+            },
+        );
+
+        Image {
+            pixels,
+            infinity_pixel: image.infinity_pixel,
+        }
+    }
+
+    fn enhance(&self, image: &Image) -> Image {
+        // Once padded, the border is made of infinity pixels, so clamping reads them past the edges
+        let padded_image = Self::pad(image, 1);
+        let padded_pixels = &padded_image.pixels;
+
+        let pixels = Grid::from_fn(padded_pixels.width(), padded_pixels.height(), |pos| {
+            let index = (-1..=1)
+                .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+                .fold(0, |index, delta| {
+                    let bit = match padded_pixels[padded_pixels.offset_clamped(pos, delta)] {
+                        Pixel::Dark => 0,
+                        Pixel::Light => 1,
+                    };
+                    (index << 1) | bit
+                });
+
+            self.algorithm[index]
+        });
+
+        // Update the infinity pixel
+        let infinity_pixel = match padded_image.infinity_pixel {
+            Pixel::Dark => self.algorithm[0b000000000],
+            Pixel::Light => self.algorithm[0b111111111],
+        };
+
+        Image {
+            pixels,
+            infinity_pixel,
+        }
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (ImageEnhancer, Image);
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day20Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day20Error> {
+        let mut lines_iter = input.lines().enumerate();
+
+        // Build enhancer from first line
+        let (_, first_line) = lines_iter.next().ok_or(Day20Error::Empty)?;
+        let enhancer = ImageEnhancer::parse(&first_line?)?;
+
+        // Build image from the rest of the lines, skipping the blank separator
+        let image_lines = lines_iter.filter(|(_, line)| !line.as_ref().is_ok_and(|l| l.is_empty()));
+        let pixels = Grid::from_lines(image_lines, |c| Pixel::try_from(c).ok())?;
+
+        if pixels.is_empty() {
+            return Err(Day20Error::EmptyImage);
+        }
+
+        Ok((enhancer, Image::new(pixels)))
+    }
+
+    fn part1((enhancer, image): &Self::Input) -> Result<usize, Day20Error> {
+        let mut image = image.clone();
+        for _ in 0..2 {
+            image = enhancer.enhance(&image);
+        }
+
+        Ok(image.get_light_pixels_count())
+    }
+
+    fn part2((enhancer, image): &Self::Input) -> Result<usize, Day20Error> {
+        let mut image = image.clone();
+        for _ in 0..50 {
+            image = enhancer.enhance(&image);
+        }
+
+        Ok(image.get_light_pixels_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day20::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day20::part1(&input).unwrap(), 35);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day20::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day20::part2(&input).unwrap(), 3351);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day20::parse("#.#\n\n#..\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the enhancement algorithm must have 512 pixels, found 3"
+        );
+
+        let algorithm = EXAMPLE.lines().next().unwrap();
+        let error =
+            Day20::parse(format!("{}\n\n#..#.\n#.o..\n", algorithm).as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 3: invalid cell 'o'");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(20);
+        let input = Day20::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day20::part1(&input).unwrap(), 5461);
+        assert_eq!(Day20::part2(&input).unwrap(), 18226);
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::BufRead;
+
+use common::error::{self, Position};
+use common::Solution;
+use itertools::Itertools;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day21Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("missing the starting position of player {player}")]
+    MissingPlayer { player: usize },
+    #[error("{position}: expected 'Player <n> starting position: <position>', got '{line}'")]
+    InvalidPlayer { position: Position, line: String },
+    #[error("{position}: invalid starting position '{token}', expected 1 to 10")]
+    InvalidPosition { position: Position, token: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Player {
+    position: usize,
+    score: usize,
+}
+
+impl Player {
+    fn new(position: usize) -> Self {
+        Self { position, score: 0 }
+    }
+
+    fn move_pos(&mut self, die: &mut dyn Die) {
+        let mut new_pos = self.position + die.roll() + die.roll() + die.roll();
+        if new_pos > 10 {
+            if new_pos.is_multiple_of(10) {
+                new_pos = 10;
+            } else {
+                new_pos %= 10;
+            }
+        }
+
+        self.score += new_pos;
+        self.position = new_pos;
+    }
+
+    fn has_won(&self) -> bool {
+        self.score >= 1000
+    }
+}
+
+trait Die {
+    fn roll(&mut self) -> usize;
+}
+
+struct DeterministicDie {
+    nsides: usize,
+    previous_roll: usize,
+    nrolls: usize,
+}
+
+impl DeterministicDie {
+    fn new() -> Self {
+        Self {
+            nsides: 100,
+            previous_roll: 0,
+            nrolls: 0,
+        }
+    }
+
+    fn get_roll_count(&self) -> usize {
+        self.nrolls
+    }
+}
+
+impl Die for DeterministicDie {
+    fn roll(&mut self) -> usize {
+        let mut new_row = self.previous_roll + 1;
+        if new_row == (self.nsides) + 1 {
+            new_row = 1;
+        }
+
+        self.nrolls += 1;
+
+        self.previous_roll = new_row;
+        new_row
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (Player, Player);
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day21Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day21Error> {
+        let mut lines_iter = input.lines().enumerate();
+
+        // First line is the first player, second line is the second player
+        let mut read_player = |player: usize| -> Result<Player, Day21Error> {
+            let (index, line_result) = lines_iter
+                .next()
+                .ok_or(Day21Error::MissingPlayer { player })?;
+            let line = line_result?;
+
+            let (_, position_str) =
+                line.split_once(": ")
+                    .ok_or_else(|| Day21Error::InvalidPlayer {
+                        position: Position::line_start(index),
+                        line: line.clone(),
+                    })?;
+
+            let invalid_position =
+                |position, token| Day21Error::InvalidPosition { position, token };
+            let position = error::parse_token(index, &line, position_str, invalid_position)?;
+            if !(1..=10).contains(&position) {
+                return Err(invalid_position(
+                    Position::of(index, &line, position_str),
+                    position_str.to_string(),
+                ));
+            }
+
+            Ok(Player::new(position))
+        };
+
+        let player1 = read_player(1)?;
+        let player2 = read_player(2)?;
+
+        Ok((player1, player2))
+    }
+
+    fn part1((player1, player2): &Self::Input) -> Result<usize, Day21Error> {
+        let mut player1 = player1.clone();
+        let mut player2 = player2.clone();
+        let mut die = DeterministicDie::new();
+
+        let mut _loser = None;
+        loop {
+            player1.move_pos(&mut die);
+            if player1.has_won() {
+                _loser = Some(&player2);
+                break;
+            }
+
+            player2.move_pos(&mut die);
+            if player2.has_won() {
+                _loser = Some(&player1);
+                break;
+            }
+        }
+
+        let loser = _loser.unwrap();
+
+        Ok(loser.score * die.get_roll_count())
+    }
+
+    fn part2((player1, player2): &Self::Input) -> Result<usize, Day21Error> {
+        let mut universes_map = HashMap::new();
+        universes_map.insert([player1.clone(), player2.clone()], 1_usize);
+
+        let mut wins = [0, 0]; // (p1 wins, p2 wins)
+
+        while !universes_map.is_empty() {
+            // Two turns, one for each player
+            for i in 0..2 {
+                // Update universes
+                let mut new_universes_map = HashMap::new();
+                for (players, &count) in universes_map.iter() {
+                    let quantum_rolls_iter = itertools::cons_tuples(
+                        (1..=3).cartesian_product(1..=3).cartesian_product(1..=3),
+                    );
+                    for (r1, r2, r3) in quantum_rolls_iter {
+                        let mut players = players.clone();
+
+                        players[i].position += r1 + r2 + r3;
+                        if players[i].position > 10 {
+                            players[i].position -= 10;
+                        }
+
+                        players[i].score += players[i].position;
+                        if players[i].score >= 21 {
+                            wins[i] += count;
+                        } else {
+                            *new_universes_map.entry(players).or_insert(0) += count;
+                        }
+                    }
+                }
+                universes_map = new_universes_map;
+            }
+        }
+
+        Ok(*wins.iter().max().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Player 1 starting position: 4
+Player 2 starting position: 8
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day21::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day21::part1(&input).unwrap(), 739785);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day21::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day21::part2(&input).unwrap(), 444356092776315);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day21::parse("Player 1 starting position: 4\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "missing the starting position of player 2"
+        );
+
+        let error = Day21::parse(
+            "Player 1 starting position: 4\nPlayer 2 starting position: 11\n".as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 29: invalid starting position '11', expected 1 to 10"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(21);
+        let input = Day21::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day21::part1(&input).unwrap(), 512442);
+        assert_eq!(Day21::part2(&input).unwrap(), 346642902541848);
+    }
+}
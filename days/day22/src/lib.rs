@@ -0,0 +1,405 @@
+use std::io;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use common::error::{self, Position};
+use common::Solution;
+use regex::Regex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day22Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(
+        "{position}: expected '<on|off> x=<x1>..<x2>,y=<y1>..<y2>,z=<z1>..<z2>', got '{line}'"
+    )]
+    InvalidStep { position: Position, line: String },
+    #[error("{position}: invalid cuboid type '{token}', expected on or off")]
+    InvalidCuboidType { position: Position, token: String },
+    #[error("{position}: invalid coordinate '{token}'")]
+    InvalidCoordinate { position: Position, token: String },
+    #[error("{position}: range '{token}' ends before it starts")]
+    InvalidRange { position: Position, token: String },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CuboidType {
+    On,
+    Off,
+}
+
+impl FromStr for CuboidType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "on" => Ok(Self::On),
+            "off" => Ok(Self::Off),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Cuboid {
+    c_type: CuboidType,
+    x_range: (isize, isize),
+    y_range: (isize, isize),
+    z_range: (isize, isize),
+}
+
+impl Cuboid {
+    // Parses the reboot step on the line with 0-based index `index`
+    fn parse_line(index: usize, line: &str) -> Result<Self, Day22Error> {
+        let cuboid_regex = Regex::new(
+            r"^(\w+)\s+x=([-]?\d+)\.\.([-]?\d+),y=([-]?\d+)\.\.([-]?\d+),z=([-]?\d+)\.\.([-]?\d+)$",
+        )
+        .unwrap();
+        let captures = cuboid_regex
+            .captures(line)
+            .ok_or_else(|| Day22Error::InvalidStep {
+                position: Position::line_start(index),
+                line: line.to_string(),
+            })?;
+
+        // The regex guarantees every capture is there
+        let capture = |i: usize| captures.get(i).unwrap().as_str();
+
+        // Parse cuboid type
+        let c_type =
+            CuboidType::from_str(capture(1)).map_err(|_| Day22Error::InvalidCuboidType {
+                position: Position::of(index, line, capture(1)),
+                token: capture(1).to_string(),
+            })?;
+
+        // Parse the x, y and z ranges
+        let parse_range = |i: usize| -> Result<(isize, isize), Day22Error> {
+            let parse_coordinate = |token: &str| {
+                error::parse_token(index, line, token, |position, token| {
+                    Day22Error::InvalidCoordinate { position, token }
+                })
+            };
+            let (start, end) = (
+                parse_coordinate(capture(i))?,
+                parse_coordinate(capture(i + 1))?,
+            );
+
+            if start > end {
+                let range_start = captures.get(i).unwrap().start();
+                let range_end = captures.get(i + 1).unwrap().end();
+                return Err(Day22Error::InvalidRange {
+                    position: Position::of(index, line, capture(i)),
+                    token: line[range_start..range_end].to_string(),
+                });
+            }
+
+            Ok((start, end))
+        };
+
+        Ok(Self {
+            c_type,
+            x_range: parse_range(2)?,
+            y_range: parse_range(4)?,
+            z_range: parse_range(6)?,
+        })
+    }
+}
+
+impl Cuboid {
+    fn get_common_range(
+        range_a: (isize, isize),
+        range_b: (isize, isize),
+    ) -> Option<(isize, isize)> {
+        if range_a.0 > range_b.1 || range_a.1 < range_b.0 {
+            None
+        } else {
+            Some((range_a.0.max(range_b.0), range_a.1.min(range_b.1)))
+        }
+    }
+
+    fn get_intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        // Calculate intersection on the x range
+        let common_x_range_opt = Self::get_common_range(other.x_range, self.x_range);
+
+        // Calculate intersection on the y range
+        let common_y_range_opt = Self::get_common_range(other.y_range, self.y_range);
+
+        // Calculate intersection on the z range
+        let common_z_range_opt = Self::get_common_range(other.z_range, self.z_range);
+
+        // If we found intersections in the three ranges, return the intersection cuboid
+        if let (Some(x_range), Some(y_range), Some(z_range)) =
+            (common_x_range_opt, common_y_range_opt, common_z_range_opt)
+        {
+            Some(Cuboid {
+                c_type: other.c_type,
+                x_range,
+                y_range,
+                z_range,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn split_from_intersection(mut self, other: &Cuboid) -> Vec<Cuboid> {
+        let intersection = self.get_intersection(other);
+        if intersection.is_none() {
+            return vec![self];
+        }
+        let intersection = intersection.unwrap();
+
+        let mut new_cubes = Vec::new();
+
+        // See if we have to split the left side of the cube
+        if intersection.x_range.0 > self.x_range.0 {
+            // Create new cube from split
+            let new_x_range = (self.x_range.0, intersection.x_range.0 - 1);
+            let new_cube = Cuboid {
+                c_type: self.c_type,
+                x_range: new_x_range,
+                y_range: self.y_range,
+                z_range: self.z_range,
+            };
+            new_cubes.push(new_cube);
+
+            // Adjust the original cube range
+            self.x_range.0 = intersection.x_range.0;
+        }
+
+        // See if we have to split the right side of the cube
+        if intersection.x_range.1 < self.x_range.1 {
+            // Create new cube from split
+            let new_x_range = (intersection.x_range.1 + 1, self.x_range.1);
+            let new_cube = Cuboid {
+                c_type: self.c_type,
+                x_range: new_x_range,
+                y_range: self.y_range,
+                z_range: self.z_range,
+            };
+            new_cubes.push(new_cube);
+
+            // Adjust the original cube range
+            self.x_range.1 = intersection.x_range.1;
+        }
+
+        // See if we have to slit the back of the cube
+        if intersection.z_range.0 > self.z_range.0 {
+            // Create new cube from split
+            let new_z_range = (self.z_range.0, intersection.z_range.0 - 1);
+            let new_cube = Cuboid {
+                c_type: self.c_type,
+                x_range: self.x_range,
+                y_range: self.y_range,
+                z_range: new_z_range,
+            };
+            new_cubes.push(new_cube);
+
+            // Adjust the original cube range
+            self.z_range.0 = intersection.z_range.0;
+        }
+
+        // See if we have to slit the front of the cube
+        if intersection.z_range.1 < self.z_range.1 {
+            // Create new cube from split
+            let new_z_range = (intersection.z_range.1 + 1, self.z_range.1);
+            let new_cube = Cuboid {
+                c_type: self.c_type,
+                x_range: self.x_range,
+                y_range: self.y_range,
+                z_range: new_z_range,
+            };
+            new_cubes.push(new_cube);
+
+            // Adjust the original cube range
+            self.z_range.1 = intersection.z_range.1;
+        }
+
+        // See if we have to split the bottom of the cube
+        if intersection.y_range.0 > self.y_range.0 {
+            // Create new cube from split
+            let new_y_range = (self.y_range.0, intersection.y_range.0 - 1);
+            let new_cube = Cuboid {
+                c_type: self.c_type,
+                x_range: self.x_range,
+                y_range: new_y_range,
+                z_range: self.z_range,
+            };
+            new_cubes.push(new_cube);
+
+            // Adjust the original cube range
+            self.y_range.0 = intersection.y_range.0;
+        }
+
+        // See if we have to split the top of the cube
+        if intersection.y_range.1 < self.y_range.1 {
+            // Create new cube from split
+            let new_y_range = (intersection.y_range.1 + 1, self.y_range.1);
+            let new_cube = Cuboid {
+                c_type: self.c_type,
+                x_range: self.x_range,
+                y_range: new_y_range,
+                z_range: self.z_range,
+            };
+            new_cubes.push(new_cube);
+
+            // Adjust the original cube range
+            self.y_range.1 = intersection.y_range.1;
+        }
+
+        new_cubes
+    }
+}
+
+#[derive(Debug)]
+struct Reactor {
+    on_cuboids: Vec<Cuboid>,
+}
+
+impl Reactor {
+    fn new() -> Self {
+        Self {
+            on_cuboids: Vec::new(),
+        }
+    }
+
+    fn execute_instruction(&mut self, new_cuboid: Cuboid) {
+        let mut new_on_cuboids = Vec::new();
+        for &cuboid in &self.on_cuboids {
+            let new_cuboids = cuboid.split_from_intersection(&new_cuboid);
+            new_on_cuboids.extend(new_cuboids);
+        }
+
+        match new_cuboid.c_type {
+            CuboidType::On => new_on_cuboids.push(new_cuboid),
+            CuboidType::Off => (),
+        };
+
+        self.on_cuboids = new_on_cuboids;
+    }
+
+    fn count_on_cubes(&self) -> usize {
+        self.on_cuboids.iter().fold(0, |on_count, cbd| {
+            let cube_count = (cbd.x_range.1 + 1 - cbd.x_range.0)
+                * (cbd.y_range.1 + 1 - cbd.y_range.0)
+                * (cbd.z_range.1 + 1 - cbd.z_range.0);
+
+            on_count + cube_count as usize
+        })
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Vec<Cuboid>, Vec<Cuboid>);
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day22Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day22Error> {
+        let lines_iter = input.lines();
+
+        let mut init_cuboids = Vec::new();
+        let mut remaining_cuboids = Vec::new();
+        for (index, line_result) in lines_iter.enumerate() {
+            let line = line_result?;
+
+            let cuboid = Cuboid::parse_line(index, &line)?;
+            if cuboid.x_range.0 < -50
+                || cuboid.x_range.1 > 50
+                || cuboid.y_range.0 < -50
+                || cuboid.y_range.1 > 50
+                || cuboid.z_range.0 < -50
+                || cuboid.z_range.1 > 50
+            {
+                remaining_cuboids.push(cuboid);
+            } else {
+                init_cuboids.push(cuboid);
+            }
+        }
+
+        Ok((init_cuboids, remaining_cuboids))
+    }
+
+    fn part1((init_cuboids, _remaining_cuboids): &Self::Input) -> Result<usize, Day22Error> {
+        let mut reactor = Reactor::new();
+
+        for cuboid in init_cuboids {
+            reactor.execute_instruction(*cuboid);
+        }
+
+        Ok(reactor.count_on_cubes())
+    }
+
+    fn part2((init_cuboids, remaining_cuboids): &Self::Input) -> Result<usize, Day22Error> {
+        let mut reactor = Reactor::new();
+
+        for cuboid in init_cuboids.iter().chain(remaining_cuboids) {
+            reactor.execute_instruction(*cuboid);
+        }
+
+        Ok(reactor.count_on_cubes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every step of the smallest example lies in the initialization region, so both parts agree
+    const EXAMPLE: &str = "\
+on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day22::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day22::part1(&input).unwrap(), 39);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day22::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day22::part2(&input).unwrap(), 39);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day22::parse(
+            "on x=10..12,y=10..12,z=10..12\ntoggle x=9..11,y=9..11,z=9..11\n".as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: invalid cuboid type 'toggle', expected on or off"
+        );
+
+        let error = Day22::parse("on x=10..12,y=12..10,z=10..12\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 15: range '12..10' ends before it starts"
+        );
+
+        let error = Day22::parse("on x=10..12,y=10..12\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected '<on|off> x=<x1>..<x2>,y=<y1>..<y2>,z=<z1>..<z2>', got 'on x=10..12,y=10..12'"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(22);
+        let input = Day22::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day22::part1(&input).unwrap(), 601104);
+        assert_eq!(Day22::part2(&input).unwrap(), 1262883317822267);
+    }
+}
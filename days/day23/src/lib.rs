@@ -0,0 +1,749 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::{self, Display};
+use std::io;
+use std::io::BufRead;
+
+use common::error::Position;
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day23Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("the diagram ends before the bottom wall of the burrow")]
+    Incomplete,
+    #[error("{position}: expected {expected}, got '{line}'")]
+    InvalidLine {
+        position: Position,
+        expected: &'static str,
+        line: String,
+    },
+    #[error("{position}: invalid cell '{token}', expected '.' or an amphipod from A to D")]
+    InvalidCell { position: Position, token: char },
+    #[error("{position}: amphipods never stop right outside a room")]
+    BlockedEntrance { position: Position },
+    #[error("{position}: empty space under an amphipod")]
+    Gap { position: Position },
+    #[error("expected {expected} amphipods of type {amphipod}, found {found}")]
+    WrongAmphipodCount {
+        amphipod: Amphipod,
+        expected: usize,
+        found: usize,
+    },
+    #[error("the amphipods can't be organized")]
+    Unsolvable,
+}
+
+const HALLWAY_LEN: usize = 11;
+const N_ROOMS: usize = 4;
+
+// Hallway cells right outside each room, amphipods walk past them but never stop there
+const ENTRANCES: [usize; N_ROOMS] = [2, 4, 6, 8];
+
+// Rows unfolded between the first and the last row of every room in part 2
+const FOLDED_ROWS: [[Amphipod; N_ROOMS]; 2] = {
+    use Amphipod::*;
+    [
+        [Desert, Copper, Bronze, Amber],
+        [Desert, Bronze, Amber, Copper],
+    ]
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    const ALL: [Amphipod; N_ROOMS] = [Self::Amber, Self::Bronze, Self::Copper, Self::Desert];
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'A' => Some(Self::Amber),
+            'B' => Some(Self::Bronze),
+            'C' => Some(Self::Copper),
+            'D' => Some(Self::Desert),
+            _ => None,
+        }
+    }
+
+    // Energy spent on every step
+    pub fn energy(self) -> usize {
+        match self {
+            Self::Amber => 1,
+            Self::Bronze => 10,
+            Self::Copper => 100,
+            Self::Desert => 1000,
+        }
+    }
+
+    // Index of the room the amphipod belongs in
+    fn room(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Amphipod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Self::Amber => 'A',
+            Self::Bronze => 'B',
+            Self::Copper => 'C',
+            Self::Desert => 'D',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+// Parses a single cell of the diagram, `None` if it isn't a cell at all
+fn parse_cell(c: char) -> Option<Option<Amphipod>> {
+    match c {
+        '.' => Some(None),
+        c => Amphipod::from_char(c).map(Some),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Hallway(usize),
+    Room { room: usize, slot: usize },
+}
+
+impl Location {
+    // Hallway cell the location is on or under
+    fn column(self) -> usize {
+        match self {
+            Self::Hallway(cell) => cell,
+            Self::Room { room, .. } => ENTRANCES[room],
+        }
+    }
+
+    // Steps between the location and the hallway
+    fn steps_to_hallway(self) -> usize {
+        match self {
+            Self::Hallway(_) => 0,
+            Self::Room { slot, .. } => slot + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hallway(cell) => write!(f, "hallway cell {}", cell + 1),
+            Self::Room { room, slot } => write!(f, "room {}, slot {}", room + 1, slot + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub amphipod: Amphipod,
+    pub from: Location,
+    pub to: Location,
+    pub steps: usize,
+}
+
+impl Move {
+    fn new(amphipod: Amphipod, from: Location, to: Location) -> Self {
+        let steps =
+            from.steps_to_hallway() + from.column().abs_diff(to.column()) + to.steps_to_hallway();
+
+        Move {
+            amphipod,
+            from,
+            to,
+            steps,
+        }
+    }
+
+    pub fn energy(&self) -> usize {
+        self.steps * self.amphipod.energy()
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} from {} to {}, {} steps for {} energy",
+            self.amphipod,
+            self.from,
+            self.to,
+            self.steps,
+            self.energy()
+        )
+    }
+}
+
+// Least energy way of organizing a burrow
+#[derive(Debug, Clone)]
+pub struct Plan {
+    pub energy: usize,
+    pub moves: Vec<Move>,
+}
+
+impl Plan {
+    // Burrow after every move, starting from the one the plan was made for
+    pub fn replay(&self, start: &Burrow) -> Vec<Step> {
+        let mut burrow = start.clone();
+        let mut total_energy = 0;
+
+        self.moves
+            .iter()
+            .enumerate()
+            .map(|(i, mv)| {
+                burrow.apply(mv);
+                total_energy += mv.energy();
+
+                Step {
+                    number: i + 1,
+                    mv: *mv,
+                    burrow: burrow.clone(),
+                    total_energy,
+                }
+            })
+            .collect()
+    }
+}
+
+// A single move of a replayed plan
+#[derive(Debug, Clone)]
+pub struct Step {
+    pub number: usize,
+    pub mv: Move,
+    pub burrow: Burrow,
+    pub total_energy: usize,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Move {}: {}, {} energy in total",
+            self.number, self.mv, self.total_energy
+        )?;
+        write!(f, "{}", self.burrow)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    hallway: [Option<Amphipod>; HALLWAY_LEN],
+    depth: usize,
+    // Slots of every room one after the other, each room from the hallway down
+    slots: Vec<Option<Amphipod>>,
+}
+
+impl Burrow {
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn room(&self, room: usize) -> &[Option<Amphipod>] {
+        &self.slots[room * self.depth..(room + 1) * self.depth]
+    }
+
+    pub fn get(&self, location: Location) -> Option<Amphipod> {
+        match location {
+            Location::Hallway(cell) => self.hallway[cell],
+            Location::Room { room, slot } => self.room(room)[slot],
+        }
+    }
+
+    fn set(&mut self, location: Location, amphipod: Option<Amphipod>) {
+        match location {
+            Location::Hallway(cell) => self.hallway[cell] = amphipod,
+            Location::Room { room, slot } => self.slots[room * self.depth + slot] = amphipod,
+        }
+    }
+
+    pub fn apply(&mut self, mv: &Move) {
+        self.set(mv.from, None);
+        self.set(mv.to, Some(mv.amphipod));
+    }
+
+    pub fn is_organized(&self) -> bool {
+        (0..N_ROOMS).all(|room| {
+            self.room(room)
+                .iter()
+                .all(|&slot| slot == Some(Amphipod::ALL[room]))
+        })
+    }
+
+    // The second half of the diagram was folded away, burrows deeper than 2 are already unfolded
+    pub fn unfolded(&self) -> Self {
+        if self.depth != 2 {
+            return self.clone();
+        }
+
+        let slots = (0..N_ROOMS)
+            .flat_map(|room| {
+                let [top, bottom] = [0, 1].map(|slot| self.room(room)[slot]);
+                [
+                    top,
+                    Some(FOLDED_ROWS[0][room]),
+                    Some(FOLDED_ROWS[1][room]),
+                    bottom,
+                ]
+            })
+            .collect();
+
+        Burrow {
+            hallway: self.hallway,
+            depth: 4,
+            slots,
+        }
+    }
+
+    // Slot an amphipod would move into, if the room only holds amphipods that belong there
+    fn free_slot(&self, room: usize) -> Option<usize> {
+        let slots = self.room(room);
+        if slots.iter().flatten().any(|&a| a != Amphipod::ALL[room]) {
+            return None;
+        }
+
+        slots.iter().rposition(Option::is_none)
+    }
+
+    // First slot of the bottom of the room that only holds amphipods that belong there
+    fn settled_from(&self, room: usize) -> usize {
+        self.room(room)
+            .iter()
+            .rposition(|&a| a != Some(Amphipod::ALL[room]))
+            .map_or(0, |slot| slot + 1)
+    }
+
+    // Slot of the amphipod that has to leave the room next, if any does
+    fn leaving_slot(&self, room: usize) -> Option<usize> {
+        let top = self.room(room).iter().position(Option::is_some)?;
+
+        (top < self.settled_from(room)).then_some(top)
+    }
+
+    // Whether the hallway is empty between two cells, not counting the first one
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let cells = if from < to {
+            from + 1..to + 1
+        } else {
+            to..from
+        };
+
+        self.hallway[cells].iter().all(Option::is_none)
+    }
+
+    // Move of an amphipod straight into its room, if it can get there
+    fn move_home(&self, amphipod: Amphipod, from: Location) -> Option<Move> {
+        let room = amphipod.room();
+        let slot = self.free_slot(room)?;
+
+        self.is_clear(from.column(), ENTRANCES[room])
+            .then(|| Move::new(amphipod, from, Location::Room { room, slot }))
+    }
+
+    // Every legal move, or only one when an amphipod can already go home since that's never worse
+    fn moves(&self) -> Vec<Move> {
+        let in_hallway = (0..HALLWAY_LEN).filter_map(|cell| {
+            self.hallway[cell].map(|amphipod| (amphipod, Location::Hallway(cell)))
+        });
+        let leaving = (0..N_ROOMS).filter_map(|room| {
+            let slot = self.leaving_slot(room)?;
+            let from = Location::Room { room, slot };
+            Some((self.get(from)?, from))
+        });
+
+        let mut moves = Vec::new();
+        for (amphipod, from) in in_hallway.chain(leaving) {
+            if let Some(mv) = self.move_home(amphipod, from) {
+                return vec![mv];
+            }
+
+            // Amphipods that leave a room stop anywhere along the hallway they can reach
+            if let Location::Room { room, .. } = from {
+                let entrance = ENTRANCES[room];
+                let left = (0..entrance).rev();
+                let right = entrance + 1..HALLWAY_LEN;
+                for cells in [left.collect::<Vec<_>>(), right.collect()] {
+                    for cell in cells {
+                        if self.hallway[cell].is_some() {
+                            break;
+                        }
+                        if !ENTRANCES.contains(&cell) {
+                            moves.push(Move::new(amphipod, from, Location::Hallway(cell)));
+                        }
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
+    // Energy needed if every amphipod could walk through the others, never more than the real cost
+    fn min_energy_left(&self) -> usize {
+        let in_hallway = (0..HALLWAY_LEN)
+            .filter_map(|cell| Some((self.hallway[cell]?, Location::Hallway(cell))));
+        let in_rooms = (0..N_ROOMS).flat_map(|room| {
+            (0..self.settled_from(room)).filter_map(move |slot| {
+                Some((self.room(room)[slot]?, Location::Room { room, slot }))
+            })
+        });
+
+        in_hallway
+            .chain(in_rooms)
+            .map(|(amphipod, from)| {
+                let room = amphipod.room();
+                let steps = match from {
+                    // Amphipods in their room still have to step aside and come back
+                    Location::Room { room: r, slot } if r == room => slot + 4,
+                    from => Move::new(amphipod, from, Location::Room { room, slot: 0 }).steps,
+                };
+                steps * amphipod.energy()
+            })
+            .sum()
+    }
+
+    // A* over the states of the burrow, returning the cheapest sequence of moves that organizes it
+    pub fn organize(&self) -> Result<Plan, Day23Error> {
+        let mut states = vec![self.clone()];
+        let mut ids = HashMap::from([(self.clone(), 0)]);
+        let mut energies = vec![0];
+        let mut came_from: Vec<Option<(usize, Move)>> = vec![None];
+
+        let mut queue = BinaryHeap::from([Reverse((self.min_energy_left(), 0, 0))]);
+        while let Some(Reverse((_, energy, id))) = queue.pop() {
+            // Stale entry, a cheaper way to this state was found after it was queued
+            if energy > energies[id] {
+                continue;
+            }
+
+            if states[id].is_organized() {
+                let mut moves = Vec::new();
+                let mut current = id;
+                while let Some((previous, mv)) = came_from[current] {
+                    moves.push(mv);
+                    current = previous;
+                }
+                moves.reverse();
+
+                return Ok(Plan { energy, moves });
+            }
+
+            let burrow = states[id].clone();
+            for mv in burrow.moves() {
+                let mut next = burrow.clone();
+                next.apply(&mv);
+                let next_energy = energy + mv.energy();
+
+                let next_id = match ids.get(&next) {
+                    Some(&next_id) if energies[next_id] <= next_energy => continue,
+                    Some(&next_id) => next_id,
+                    None => {
+                        states.push(next.clone());
+                        energies.push(usize::MAX);
+                        came_from.push(None);
+                        ids.insert(next.clone(), states.len() - 1);
+                        states.len() - 1
+                    }
+                };
+
+                energies[next_id] = next_energy;
+                came_from[next_id] = Some((id, mv));
+                queue.push(Reverse((
+                    next_energy + next.min_energy_left(),
+                    next_energy,
+                    next_id,
+                )));
+            }
+        }
+
+        Err(Day23Error::Unsolvable)
+    }
+
+    // Parses the rows of rooms and the bottom wall, once the hallway has been read
+    fn parse_rooms(
+        lines: impl Iterator<Item = (usize, io::Result<String>)>,
+    ) -> Result<Vec<[Option<Amphipod>; N_ROOMS]>, Day23Error> {
+        let mut rows = Vec::new();
+        for (index, line_result) in lines {
+            let line = line_result?;
+
+            let trimmed = line.trim();
+            if !rows.is_empty() && !trimmed.is_empty() && trimmed.chars().all(|c| c == '#') {
+                return Ok(rows);
+            }
+
+            let invalid_line = || Day23Error::InvalidLine {
+                position: Position::line_start(index),
+                expected: "a row of rooms like '  #A#B#C#D#'",
+                line: line.clone(),
+            };
+
+            let chars: Vec<char> = line.trim_end().chars().collect();
+            if chars.len() < 2 * N_ROOMS + 3 {
+                return Err(invalid_line());
+            }
+
+            let mut row = [None; N_ROOMS];
+            for (column, &c) in chars.iter().enumerate() {
+                match ENTRANCES.iter().position(|&e| e + 1 == column) {
+                    Some(room) => {
+                        row[room] = parse_cell(c).ok_or(Day23Error::InvalidCell {
+                            position: Position::new(index + 1, column + 1),
+                            token: c,
+                        })?;
+                    }
+                    None if c == '#' || c == ' ' => (),
+                    None => return Err(invalid_line()),
+                }
+            }
+
+            // Amphipods fill rooms from the bottom up
+            if let Some(above) = rows.last() {
+                let gap = (0..N_ROOMS).find(|&room| above[room].is_some() && row[room].is_none());
+                if let Some(room) = gap {
+                    return Err(Day23Error::Gap {
+                        position: Position::new(index + 1, ENTRANCES[room] + 2),
+                    });
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Err(Day23Error::Incomplete)
+    }
+}
+
+impl Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cell = |c: Option<Amphipod>| c.map_or(".".to_string(), |a| a.to_string());
+
+        writeln!(f, "{}", "#".repeat(HALLWAY_LEN + 2))?;
+        let hallway: String = self.hallway.iter().map(|&c| cell(c)).collect();
+        writeln!(f, "#{}#", hallway)?;
+
+        for slot in 0..self.depth {
+            let row: Vec<String> = (0..N_ROOMS)
+                .map(|room| cell(self.room(room)[slot]))
+                .collect();
+            let (outer, inner) = if slot == 0 { ("##", "#") } else { ("  ", "") };
+            writeln!(f, "{}#{}#{}", outer, row.join("#"), inner.repeat(2))?;
+        }
+
+        write!(f, "  {}", "#".repeat(2 * N_ROOMS + 1))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day23Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day23Error> {
+        let mut lines = input.lines().enumerate();
+        let mut next_line = || -> Result<(usize, String), Day23Error> {
+            let (index, line_result) = lines.next().ok_or(Day23Error::Incomplete)?;
+            Ok((index, line_result?))
+        };
+
+        let (index, line) = next_line()?;
+        if line.is_empty() || !line.chars().all(|c| c == '#') {
+            return Err(Day23Error::InvalidLine {
+                position: Position::line_start(index),
+                expected: "the top wall of the burrow",
+                line,
+            });
+        }
+
+        // The hallway is the only line whose cells don't all belong to rooms
+        let (index, line) = next_line()?;
+        let chars: Vec<char> = line.chars().collect();
+        if chars.len() != HALLWAY_LEN + 2 || chars[0] != '#' || chars[HALLWAY_LEN + 1] != '#' {
+            return Err(Day23Error::InvalidLine {
+                position: Position::line_start(index),
+                expected: "a hallway like '#...........#'",
+                line,
+            });
+        }
+
+        let mut hallway = [None; HALLWAY_LEN];
+        for (cell, &c) in chars[1..=HALLWAY_LEN].iter().enumerate() {
+            let position = Position::new(index + 1, cell + 2);
+            hallway[cell] = parse_cell(c).ok_or(Day23Error::InvalidCell { position, token: c })?;
+
+            if hallway[cell].is_some() && ENTRANCES.contains(&cell) {
+                return Err(Day23Error::BlockedEntrance { position });
+            }
+        }
+
+        let rows = Burrow::parse_rooms(lines)?;
+        let depth = rows.len();
+        let slots = (0..N_ROOMS)
+            .flat_map(|room| rows.iter().map(move |row| row[room]))
+            .collect();
+        let burrow = Burrow {
+            hallway,
+            depth,
+            slots,
+        };
+
+        // Every room must end up full of its own amphipods
+        for amphipod in Amphipod::ALL {
+            let found = burrow
+                .hallway
+                .iter()
+                .chain(&burrow.slots)
+                .filter(|&&a| a == Some(amphipod))
+                .count();
+            if found != depth {
+                return Err(Day23Error::WrongAmphipodCount {
+                    amphipod,
+                    expected: depth,
+                    found,
+                });
+            }
+        }
+
+        Ok(burrow)
+    }
+
+    fn part1(burrow: &Self::Input) -> Result<usize, Day23Error> {
+        Ok(burrow.organize()?.energy)
+    }
+
+    fn part2(burrow: &Self::Input) -> Result<usize, Day23Error> {
+        Ok(burrow.unfolded().organize()?.energy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day23::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day23::part1(&input).unwrap(), 12521);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day23::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day23::part2(&input).unwrap(), 44169);
+    }
+
+    #[test]
+    fn plan_and_diagram() {
+        let burrow = Day23::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(burrow.to_string() + "\n", EXAMPLE);
+
+        // Replaying the moves organizes the burrow for exactly the energy of the plan
+        let unfolded = burrow.unfolded();
+        let plan = unfolded.organize().unwrap();
+        let mut replayed = unfolded.clone();
+        for mv in &plan.moves {
+            replayed.apply(mv);
+        }
+
+        assert!(replayed.is_organized());
+        assert_eq!(plan.moves.iter().map(Move::energy).sum::<usize>(), 44169);
+        assert_eq!(
+            Day23::parse(unfolded.to_string().as_bytes()).unwrap(),
+            unfolded
+        );
+    }
+
+    #[test]
+    fn replay() {
+        let burrow = Day23::parse(EXAMPLE.as_bytes()).unwrap();
+        let plan = burrow.organize().unwrap();
+        let steps = plan.replay(&burrow);
+
+        assert_eq!(steps.len(), plan.moves.len());
+        assert!(steps.last().unwrap().burrow.is_organized());
+        assert_eq!(steps.last().unwrap().total_energy, 12521);
+
+        // The cheapest plan starts by moving the B out of the third room, as in the puzzle text
+        assert_eq!(
+            steps[0].to_string(),
+            "\
+Move 1: B from room 3, slot 1 to hallway cell 4, 4 steps for 40 energy, 40 energy in total
+#############
+#...B.......#
+###B#C#.#D###
+  #A#D#C#A#
+  #########"
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error =
+            Day23::parse("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#\n".as_bytes())
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the diagram ends before the bottom wall of the burrow"
+        );
+
+        let error = Day23::parse(EXAMPLE.replace("###B#C", "###B#E").as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 6: invalid cell 'E', expected '.' or an amphipod from A to D"
+        );
+
+        let error = Day23::parse(EXAMPLE.replace("###B#C", "###B#B").as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "expected 2 amphipods of type B, found 3");
+
+        let error = Day23::parse(
+            EXAMPLE
+                .replace("#...", "#.A.")
+                .replace("#A#D", "#.#D")
+                .as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 4: empty space under an amphipod"
+        );
+    }
+
+    #[test]
+    fn unsolvable() {
+        // A and D block each other's way home
+        let burrow =
+            Day23::parse("#############\n#...D.A.....#\n###.#B#C#.###\n  #########\n".as_bytes())
+                .unwrap();
+
+        assert!(matches!(burrow.organize(), Err(Day23Error::Unsolvable)));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(23);
+        let input = Day23::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day23::part1(&input).unwrap(), 14348);
+        assert_eq!(Day23::part2(&input).unwrap(), 40954);
+    }
+}
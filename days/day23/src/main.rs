@@ -1,5 +1,78 @@
-fn main() {
+use std::error::Error;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use common::cli::DayArgs;
+use common::input::InputArgs;
+use common::Solution;
+use day23::Day23;
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    day: DayArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the burrow after every move of the least energy plan
+    Replay {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Unfold the burrow first, as in part 2
+        #[arg(long)]
+        unfold: bool,
+
+        /// Redraw the burrow in place, waiting this many milliseconds between moves
+        #[arg(long, value_name = "MS")]
+        animate: Option<u64>,
+    },
+}
+
+fn replay(input: &InputArgs, unfold: bool, animate: Option<u64>) -> Result<(), Box<dyn Error>> {
+    let mut burrow = Day23::parse(input.open(23)?)?;
+    if unfold {
+        burrow = burrow.unfolded();
+    }
+    let plan = burrow.organize()?;
+
+    // Animations clear the terminal before every frame instead of scrolling
+    let frame = |text: String| match animate {
+        Some(ms) => {
+            println!("\x1b[2J\x1b[H{}", text);
+            thread::sleep(Duration::from_millis(ms));
+        }
+        None => println!("{}\n", text),
+    };
+
+    frame(format!("Start\n{}", burrow));
+    for step in plan.replay(&burrow) {
+        frame(step.to_string());
+    }
     println!(
-        "I did this one completely by hand, not the kind of problem I like to solve with code."
+        "Organized in {} moves for {} energy",
+        plan.moves.len(),
+        plan.energy
     );
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    common::cli::exit(match &args.command {
+        Some(Command::Replay {
+            input,
+            unfold,
+            animate,
+        }) => replay(input, *unfold, *animate),
+        None => common::cli::run::<Day23>(23, &args.day),
+    })
 }
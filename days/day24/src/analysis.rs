@@ -0,0 +1,291 @@
+use std::fmt::{self, Display};
+
+use crate::{check_digit_count, Day24Error, Instruction, Operand, Register};
+
+// Instructions MONAD runs for every digit, the parameters are all that change between blocks
+const BLOCK_LEN: usize = 18;
+
+pub(crate) fn block_template(div_z: i64, add_x: i64, add_y: i64) -> [Instruction; BLOCK_LEN] {
+    use crate::Register::{W, X, Y, Z};
+    use Instruction::*;
+    use Operand::*;
+
+    [
+        Inp(W),
+        Mul(X, Literal(0)),
+        Add(X, Register(Z)),
+        Mod(X, Literal(26)),
+        Div(Z, Literal(div_z)),
+        Add(X, Literal(add_x)),
+        Eql(X, Register(W)),
+        Eql(X, Literal(0)),
+        Mul(Y, Literal(0)),
+        Add(Y, Literal(25)),
+        Mul(Y, Register(X)),
+        Add(Y, Literal(1)),
+        Mul(Z, Register(Y)),
+        Mul(Y, Literal(0)),
+        Add(Y, Register(W)),
+        Add(Y, Literal(add_y)),
+        Mul(Y, Register(X)),
+        Add(Z, Register(Y)),
+    ]
+}
+
+// A block reads digit w, then computes
+//   x = z % 26 + add_x != w
+//   z = z / div_z
+//   if x { z = z * 26 + w + add_y }
+// so z works as a stack of base 26 digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub div_z: i64,
+    pub add_x: i64,
+    pub add_y: i64,
+}
+
+impl Block {
+    fn parse(instructions: &[Instruction]) -> Option<Self> {
+        let (
+            Instruction::Div(Register::Z, Operand::Literal(div_z)),
+            Instruction::Add(Register::X, Operand::Literal(add_x)),
+            Instruction::Add(Register::Y, Operand::Literal(add_y)),
+        ) = (instructions[4], instructions[5], instructions[15])
+        else {
+            return None;
+        };
+
+        (instructions == block_template(div_z, add_x, add_y)).then_some(Block {
+            div_z,
+            add_x,
+            add_y,
+        })
+    }
+
+    // Pushed values must stay a single non-zero base 26 digit for any w, or z stops being a stack
+    fn pushes_digit(&self) -> bool {
+        (0..=16).contains(&self.add_y)
+    }
+
+    // Blocks that don't divide z always push, since z % 26 + add_x can't be a digit
+    fn is_push(&self) -> bool {
+        self.div_z == 1 && self.add_x > 9 && self.pushes_digit()
+    }
+
+    fn is_pop(&self) -> bool {
+        self.div_z == 26 && self.pushes_digit()
+    }
+}
+
+// The digit read by the `pop` block must be the one read by the `push` block plus `offset`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub push: usize,
+    pub pop: usize,
+    pub offset: i64,
+}
+
+impl Constraint {
+    // Ranges of the pushed and popped digits that satisfy the constraint
+    fn ranges(&self) -> Option<((i64, i64), (i64, i64))> {
+        if self.offset.abs() > 8 {
+            return None;
+        }
+
+        let push = (1 - self.offset.min(0), 9 - self.offset.max(0));
+        Some((push, (push.0 + self.offset, push.1 + self.offset)))
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        write!(
+            f,
+            "digit {} = digit {} {} {}",
+            self.pop + 1,
+            self.push + 1,
+            sign,
+            self.offset.abs()
+        )?;
+
+        match self.ranges() {
+            Some(((push_min, push_max), (pop_min, pop_max))) => write!(
+                f,
+                ": digit {} is {} to {}, digit {} is {} to {}",
+                self.push + 1,
+                push_min,
+                push_max,
+                self.pop + 1,
+                pop_min,
+                pop_max
+            ),
+            None => write!(f, ": no pair of digits satisfies it"),
+        }
+    }
+}
+
+// Digit constraints of a program with the MONAD structure, which make z end up at 0 exactly when all hold
+#[derive(Debug, Clone)]
+pub struct Analysis {
+    pub blocks: Vec<Block>,
+    pub constraints: Vec<Constraint>,
+}
+
+impl Analysis {
+    // `None` when the program doesn't follow the MONAD structure
+    pub fn new(program: &[Instruction]) -> Option<Self> {
+        if program.is_empty() || !program.len().is_multiple_of(BLOCK_LEN) {
+            return None;
+        }
+
+        let blocks = program
+            .chunks(BLOCK_LEN)
+            .map(Block::parse)
+            .collect::<Option<Vec<_>>>()?;
+
+        // Every pop is matched with the latest push still on the stack, which must end up empty
+        let mut stack = Vec::new();
+        let mut constraints = Vec::new();
+        for (i, block) in blocks.iter().enumerate() {
+            if block.is_push() {
+                stack.push(i);
+            } else if block.is_pop() {
+                let push = stack.pop()?;
+                constraints.push(Constraint {
+                    push,
+                    pop: i,
+                    offset: blocks[push].add_y + block.add_x,
+                });
+            } else {
+                return None;
+            }
+        }
+
+        stack.is_empty().then_some(Analysis {
+            blocks,
+            constraints,
+        })
+    }
+
+    // Smallest and largest model numbers, failing when some constraint can't be satisfied
+    pub fn model_numbers(&self) -> Result<(usize, usize), Day24Error> {
+        // Every block reads a digit, and all of them must fit in a usize
+        check_digit_count(self.blocks.len())?;
+
+        let mut min = vec![0; self.blocks.len()];
+        let mut max = vec![0; self.blocks.len()];
+        for constraint in &self.constraints {
+            let ((push_min, push_max), (pop_min, pop_max)) =
+                constraint.ranges().ok_or(Day24Error::NoModelNumber)?;
+            (min[constraint.push], min[constraint.pop]) = (push_min, pop_min);
+            (max[constraint.push], max[constraint.pop]) = (push_max, pop_max);
+        }
+
+        let number = |digits: &[i64]| digits.iter().fold(0, |acc, &d| acc * 10 + d as usize);
+
+        Ok((number(&min), number(&max)))
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, block) in self.blocks.iter().enumerate() {
+            write!(
+                f,
+                "Block {:>2} (div z {:>2}, add x {:>3}, add y {:>2}): ",
+                i + 1,
+                block.div_z,
+                block.add_x,
+                block.add_y
+            )?;
+
+            match self.constraints.iter().find(|c| c.pop == i) {
+                Some(c) => writeln!(
+                    f,
+                    "pops digit {1} + {2}, and only pushes nothing back if digit {0} = digit {1} + {2} {3} {4}",
+                    i + 1,
+                    c.push + 1,
+                    self.blocks[c.push].add_y,
+                    if block.add_x < 0 { '-' } else { '+' },
+                    block.add_x.abs()
+                )?,
+                None => writeln!(f, "pushes digit {} + {}", i + 1, block.add_y)?,
+            }
+        }
+
+        writeln!(f, "\nz ends up at 0 when every popped digit matches:")?;
+        for constraint in &self.constraints {
+            writeln!(f, "  {}", constraint)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Monad;
+
+    // Four blocks, pushing the first two digits and popping them in reverse order
+    fn program() -> Vec<Instruction> {
+        [(1, 12, 6), (1, 11, 4), (26, -7, 3), (26, -2, 15)]
+            .into_iter()
+            .flat_map(|(div_z, add_x, add_y)| block_template(div_z, add_x, add_y))
+            .collect()
+    }
+
+    #[test]
+    fn constraints() {
+        let analysis = Analysis::new(&program()).unwrap();
+
+        assert_eq!(
+            analysis.constraints,
+            [
+                Constraint {
+                    push: 1,
+                    pop: 2,
+                    offset: -3
+                },
+                Constraint {
+                    push: 0,
+                    pop: 3,
+                    offset: 4
+                }
+            ]
+        );
+        assert_eq!(analysis.model_numbers().unwrap(), (1415, 5969));
+        assert_eq!(
+            analysis.constraints[0].to_string(),
+            "digit 3 = digit 2 - 3: digit 2 is 4 to 9, digit 3 is 1 to 6"
+        );
+    }
+
+    #[test]
+    fn agrees_with_search() {
+        let program = program();
+        let text: String = program.iter().map(|i| format!("{}\n", i)).collect();
+        let monad = Monad::parse(text.as_bytes()).unwrap();
+
+        assert_eq!(
+            crate::search(monad.program()),
+            Analysis::new(&program).unwrap().model_numbers().ok()
+        );
+    }
+
+    #[test]
+    fn unsupported_programs() {
+        let mut program = program();
+
+        // A pop without a push before it
+        assert!(Analysis::new(&program[2 * BLOCK_LEN..]).is_none());
+
+        // A push that's never popped
+        assert!(Analysis::new(&program[..3 * BLOCK_LEN]).is_none());
+
+        // A block that doesn't follow the template
+        program[1] = Instruction::Mul(Register::X, Operand::Literal(1));
+        assert!(Analysis::new(&program).is_none());
+    }
+}
@@ -0,0 +1,721 @@
+mod analysis;
+mod optimize;
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::io;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use common::error::Position;
+use common::Solution;
+use rayon::prelude::*;
+use thiserror::Error;
+
+pub use analysis::{Analysis, Block, Constraint};
+pub use optimize::{Live, Op, Optimized, Statement};
+
+#[derive(Debug, Error)]
+pub enum Day24Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("{position}: invalid instruction '{token}'")]
+    InvalidInstruction { position: Position, token: String },
+    #[error("{position}: invalid register '{token}', expected w, x, y or z")]
+    InvalidRegister { position: Position, token: String },
+    #[error("{position}: invalid operand '{token}', expected a register or a number")]
+    InvalidOperand { position: Position, token: String },
+    #[error("{position}: missing operand")]
+    MissingOperand { position: Position },
+    #[error("instruction {instruction}: ran out of input")]
+    MissingInput { instruction: usize },
+    #[error("instruction {instruction}: division by zero")]
+    DivisionByZero { instruction: usize },
+    #[error("instruction {instruction}: modulo of a negative number or by a non-positive one")]
+    InvalidModulo { instruction: usize },
+    #[error("instruction {instruction}: overflow")]
+    Overflow { instruction: usize },
+    #[error("no model number is accepted by the program")]
+    NoModelNumber,
+    #[error("invalid model number '{number}', expected digits 1 to 9")]
+    InvalidModelNumber { number: String },
+    #[error("model number has {found} digits, but the program reads {expected}")]
+    WrongDigitCount { expected: usize, found: usize },
+    #[error("model numbers of {found} digits don't fit in {max}")]
+    TooManyDigits { found: usize, max: usize },
+}
+
+// Model numbers are built in a usize, which holds any number of this many digits
+pub(crate) const MAX_DIGITS: usize = usize::MAX.ilog10() as usize;
+
+pub(crate) fn check_digit_count(found: usize) -> Result<(), Day24Error> {
+    if found > MAX_DIGITS {
+        return Err(Day24Error::TooManyDigits {
+            found,
+            max: MAX_DIGITS,
+        });
+    }
+
+    Ok(())
+}
+
+// Values of the x, y, z and w registers, in that order
+pub type Registers = [i64; 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl FromStr for Register {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "x" => Ok(Self::X),
+            "y" => Ok(Self::Y),
+            "z" => Ok(Self::Z),
+            "w" => Ok(Self::W),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::X => "x",
+            Self::Y => "y",
+            Self::Z => "z",
+            Self::W => "w",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Literal(i64),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Register(register) => write!(f, "{}", register),
+            Self::Literal(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl Instruction {
+    // Parses the line with 0-based index `index`, so errors point at the right place
+    fn parse_line(index: usize, line: &str) -> Result<Self, Day24Error> {
+        let mut words = line.split(' ');
+        let mut operand = || {
+            words.next().ok_or(Day24Error::MissingOperand {
+                position: Position::new(index + 1, line.chars().count() + 1),
+            })
+        };
+        let register = |token: &str| {
+            token.parse().map_err(|_| Day24Error::InvalidRegister {
+                position: Position::of(index, line, token),
+                token: token.to_string(),
+            })
+        };
+
+        let name = operand()?;
+        let instruction: fn(Register, Operand) -> Self = match name {
+            "inp" => return Ok(Self::Inp(register(operand()?)?)),
+            "add" => Self::Add,
+            "mul" => Self::Mul,
+            "div" => Self::Div,
+            "mod" => Self::Mod,
+            "eql" => Self::Eql,
+            _ => {
+                return Err(Day24Error::InvalidInstruction {
+                    position: Position::of(index, line, name),
+                    token: name.to_string(),
+                })
+            }
+        };
+
+        // The first operand is always a register, the second can also be a number
+        let a = register(operand()?)?;
+        let b = operand()?;
+        let b = match (b.parse(), b.parse()) {
+            (Ok(register), _) => Operand::Register(register),
+            (_, Ok(value)) => Operand::Literal(value),
+            _ => {
+                return Err(Day24Error::InvalidOperand {
+                    position: Position::of(index, line, b),
+                    token: b.to_string(),
+                })
+            }
+        };
+
+        Ok(instruction(a, b))
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inp(a) => write!(f, "inp {}", a),
+            Self::Add(a, b) => write!(f, "add {} {}", a, b),
+            Self::Mul(a, b) => write!(f, "mul {} {}", a, b),
+            Self::Div(a, b) => write!(f, "div {} {}", a, b),
+            Self::Mod(a, b) => write!(f, "mod {} {}", a, b),
+            Self::Eql(a, b) => write!(f, "eql {} {}", a, b),
+        }
+    }
+}
+
+fn read(index: usize, input: &mut impl Iterator<Item = i64>) -> Result<i64, Day24Error> {
+    input.next().ok_or(Day24Error::MissingInput {
+        instruction: index + 1,
+    })
+}
+
+fn add(index: usize, a: i64, b: i64) -> Result<i64, Day24Error> {
+    a.checked_add(b).ok_or(Day24Error::Overflow {
+        instruction: index + 1,
+    })
+}
+
+fn multiply(index: usize, a: i64, b: i64) -> Result<i64, Day24Error> {
+    a.checked_mul(b).ok_or(Day24Error::Overflow {
+        instruction: index + 1,
+    })
+}
+
+fn divide(index: usize, a: i64, b: i64) -> Result<i64, Day24Error> {
+    match b {
+        0 => Err(Day24Error::DivisionByZero {
+            instruction: index + 1,
+        }),
+        b => a.checked_div(b).ok_or(Day24Error::Overflow {
+            instruction: index + 1,
+        }),
+    }
+}
+
+fn modulo(index: usize, a: i64, b: i64) -> Result<i64, Day24Error> {
+    if a >= 0 && b > 0 {
+        Ok(a % b)
+    } else {
+        Err(Day24Error::InvalidModulo {
+            instruction: index + 1,
+        })
+    }
+}
+
+// Arithmetic logic unit of the submarine, programs read their input one number at a time
+#[derive(Debug, Clone, Default)]
+pub struct Alu {
+    pub registers: Registers,
+}
+
+impl Alu {
+    pub fn new(registers: Registers) -> Self {
+        Alu { registers }
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.registers[register.index()],
+            Operand::Literal(value) => value,
+        }
+    }
+
+    // Executes the instruction at `index` of its program, the index only shows up in errors
+    pub fn execute(
+        &mut self,
+        index: usize,
+        instruction: Instruction,
+        input: &mut impl Iterator<Item = i64>,
+    ) -> Result<(), Day24Error> {
+        let (a, value) = match instruction {
+            Instruction::Inp(a) => (a, read(index, input)?),
+            Instruction::Add(a, b) => (a, add(index, self.registers[a.index()], self.value(b))?),
+            Instruction::Mul(a, b) => (
+                a,
+                multiply(index, self.registers[a.index()], self.value(b))?,
+            ),
+            Instruction::Div(a, b) => (a, divide(index, self.registers[a.index()], self.value(b))?),
+            Instruction::Mod(a, b) => (a, modulo(index, self.registers[a.index()], self.value(b))?),
+            Instruction::Eql(a, b) => (a, (self.registers[a.index()] == self.value(b)) as i64),
+        };
+
+        self.registers[a.index()] = value;
+
+        Ok(())
+    }
+
+    // Runs a whole program, `offset` being the index of its first instruction
+    pub fn run(
+        &mut self,
+        offset: usize,
+        program: &[Instruction],
+        input: &mut impl Iterator<Item = i64>,
+    ) -> Result<(), Day24Error> {
+        for (i, &instruction) in program.iter().enumerate() {
+            self.execute(offset + i, instruction, input)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Statements from an `inp` up to the next one, along with the registers read after the `inp`
+struct Pass<'a> {
+    input: Register,
+    live: Live,
+    statements: &'a [Statement],
+}
+
+// Splits the program on every `inp`, the statements before the first one run on their own
+fn split_passes(program: &Optimized) -> (&[Statement], Vec<Pass<'_>>) {
+    let statements = &program.statements;
+    let starts: Vec<usize> = (0..statements.len())
+        .filter(|&i| matches!(statements[i].op, Op::Inp(_)))
+        .collect();
+
+    let passes = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).copied().unwrap_or(statements.len());
+            let Op::Inp(input) = statements[start].op else {
+                unreachable!()
+            };
+
+            Pass {
+                input,
+                live: program.live_after(start),
+                statements: &statements[start + 1..end],
+            }
+        })
+        .collect();
+
+    (
+        &statements[..starts.first().copied().unwrap_or(statements.len())],
+        passes,
+    )
+}
+
+// Searches every model number accepted by the program, returning the smallest and largest ones.
+// Runs that hit an invalid instruction are dropped, the program would crash on those model numbers
+fn search(program: &[Instruction]) -> Option<(usize, usize)> {
+    let optimized = Optimized::new(program);
+    let (prelude, passes) = split_passes(&optimized);
+    let run = |alu: &mut Alu, statements: &[Statement]| {
+        statements
+            .iter()
+            .try_for_each(|&statement| alu.execute_statement(statement, &mut std::iter::empty()))
+    };
+
+    let mut alu = Alu::default();
+    run(&mut alu, prelude).ok()?;
+    let mut state = vec![(alu.registers, (0, 0))];
+
+    for pass in &passes {
+        let r = pass.input.index();
+
+        // Clear the registers that aren't read anymore, including the one about to be written,
+        // so that states only differing in those get merged
+        state.par_iter_mut().for_each(|k| {
+            for (value, live) in k.0.iter_mut().zip(pass.live) {
+                if !live {
+                    *value = 0;
+                }
+            }
+            k.0[r] = 0;
+        });
+
+        // Sort by register state, then do single-pass compaction
+        state.par_sort_unstable_by_key(|k| k.0);
+        let mut i = 0;
+        let mut j = 1;
+        while j < state.len() {
+            if state[i].0 == state[j].0 {
+                let (imin, imax) = state[i].1;
+                let (jmin, jmax) = state[j].1;
+                state[i].1 = (imin.min(jmin), imax.max(jmax));
+            } else {
+                i += 1;
+                state[i] = state[j];
+            }
+            j += 1;
+        }
+        state.truncate(i + 1);
+
+        state = (1..=9)
+            .into_par_iter()
+            .flat_map(|digit| {
+                state.par_iter().filter_map(move |(regs, (min, max))| {
+                    let mut alu = Alu::new(*regs);
+                    alu.registers[r] = digit;
+                    run(&mut alu, pass.statements).ok()?;
+
+                    // The number of digits was checked before searching, so this can't overflow
+                    let digit = digit as usize;
+                    Some((alu.registers, (min * 10 + digit, max * 10 + digit)))
+                })
+            })
+            .collect();
+    }
+
+    state
+        .par_iter()
+        .filter(|(k, _)| k[Register::Z.index()] == 0)
+        .map(|(_, v)| *v)
+        .reduce_with(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+}
+
+// Registers around a single instruction of a traced run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceStep {
+    pub index: usize,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+}
+
+impl TraceStep {
+    pub fn z_change(&self) -> Ordering {
+        let z = Register::Z.index();
+        self.after[z].cmp(&self.before[z])
+    }
+}
+
+impl Display for TraceStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z, w] = self.after;
+        write!(
+            f,
+            "{:>4}  {:<12} [{}, {}, {}, {}]",
+            self.index + 1,
+            self.instruction.to_string(),
+            x,
+            y,
+            z,
+            w
+        )
+    }
+}
+
+// Run of the program on a single model number, which is valid when z ends up at 0
+#[derive(Debug, Clone)]
+pub struct Validation {
+    pub steps: Vec<TraceStep>,
+    pub registers: Registers,
+}
+
+impl Validation {
+    pub fn is_valid(&self) -> bool {
+        self.registers[Register::Z.index()] == 0
+    }
+}
+
+// The MONAD program
+#[derive(Debug)]
+pub struct Monad {
+    program: Vec<Instruction>,
+}
+
+impl Monad {
+    pub fn parse<R: BufRead>(input: R) -> Result<Self, Day24Error> {
+        let mut program = Vec::new();
+        for (index, line_result) in input.lines().enumerate() {
+            let line = line_result?;
+            program.push(Instruction::parse_line(index, &line)?);
+        }
+
+        Ok(Monad { program })
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn optimized(&self) -> Optimized {
+        Optimized::new(&self.program)
+    }
+
+    // `None` when the program doesn't follow the MONAD structure
+    pub fn analysis(&self) -> Option<Analysis> {
+        Analysis::new(&self.program)
+    }
+
+    // Runs the program on `number`, keeping the registers after every instruction
+    pub fn validate(&self, number: &str) -> Result<Validation, Day24Error> {
+        let digits = number
+            .chars()
+            .map(|c| match c {
+                '1'..='9' => Ok(c as i64 - '0' as i64),
+                _ => Err(Day24Error::InvalidModelNumber {
+                    number: number.to_string(),
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Leftover digits would go unnoticed by the ALU, missing ones are reported by it
+        let expected = self.digit_count();
+        if digits.len() > expected {
+            return Err(Day24Error::WrongDigitCount {
+                expected,
+                found: digits.len(),
+            });
+        }
+
+        let mut alu = Alu::default();
+        let mut input = digits.into_iter();
+        let mut steps = Vec::with_capacity(self.program.len());
+        for (index, &instruction) in self.program.iter().enumerate() {
+            let before = alu.registers;
+            alu.execute(index, instruction, &mut input)?;
+            steps.push(TraceStep {
+                index,
+                instruction,
+                before,
+                after: alu.registers,
+            });
+        }
+
+        Ok(Validation {
+            steps,
+            registers: alu.registers,
+        })
+    }
+
+    // Digits of a model number, one per `inp`
+    fn digit_count(&self) -> usize {
+        self.program
+            .iter()
+            .filter(|instruction| matches!(instruction, Instruction::Inp(_)))
+            .count()
+    }
+
+    // Smallest and largest model numbers accepted by the program
+    pub fn model_numbers(&self) -> Result<(usize, usize), Day24Error> {
+        check_digit_count(self.digit_count())?;
+
+        // Other programs fall back to the exhaustive search
+        match self.analysis() {
+            Some(analysis) => analysis.model_numbers(),
+            None => search(&self.program).ok_or(Day24Error::NoModelNumber),
+        }
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    // Smallest and largest model numbers
+    type Input = (usize, usize);
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Day24Error;
+
+    // Both parts come out of the same analysis or search, so it's done along with parsing
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day24Error> {
+        Monad::parse(input)?.model_numbers()
+    }
+
+    fn part1(&(_min, max): &Self::Input) -> Result<usize, Day24Error> {
+        Ok(max)
+    }
+
+    fn part2(&(min, _max): &Self::Input) -> Result<usize, Day24Error> {
+        Ok(min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Binary digits of the input, from the example in the puzzle text
+    const BINARY: &str = "\
+inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2
+";
+
+    #[test]
+    fn alu() {
+        let monad = Monad::parse(BINARY.as_bytes()).unwrap();
+        let mut alu = Alu::default();
+        alu.run(0, monad.program(), &mut [13].into_iter()).unwrap();
+
+        assert_eq!(alu.registers, [1, 0, 1, 1]);
+
+        let error = alu
+            .run(0, monad.program(), &mut std::iter::empty())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "instruction 1: ran out of input");
+
+        let monad = Monad::parse("inp x\nmul x -1\nmod x 3\n".as_bytes()).unwrap();
+        let error = Alu::default()
+            .run(0, monad.program(), &mut [4].into_iter())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "instruction 3: modulo of a negative number or by a non-positive one"
+        );
+
+        let monad = Monad::parse("inp x\nmul x 999999999999\nmul x x\n".as_bytes()).unwrap();
+        let error = Alu::default()
+            .run(0, monad.program(), &mut [9].into_iter())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "instruction 3: overflow");
+
+        let monad = Monad::parse("add z 9223372036854775807\nadd z 1\n".as_bytes()).unwrap();
+        let error = Alu::default()
+            .run(0, monad.program(), &mut std::iter::empty())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "instruction 2: overflow");
+
+        let monad = Monad::parse("add z -9223372036854775808\ndiv z -1\n".as_bytes()).unwrap();
+        let error = Alu::default()
+            .run(0, monad.program(), &mut std::iter::empty())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "instruction 2: overflow");
+    }
+
+    #[test]
+    fn search_small_program() {
+        // Two digits where the second one must be 2 more than the first
+        let program = "\
+inp w
+add z w
+inp w
+add z 2
+eql z w
+eql z 0
+";
+        let model_numbers = Day24::parse(program.as_bytes()).unwrap();
+
+        assert_eq!(Day24::part1(&model_numbers).unwrap(), 79);
+        assert_eq!(Day24::part2(&model_numbers).unwrap(), 13);
+
+        assert!(matches!(
+            Day24::parse("inp w\nadd z w\n".as_bytes()),
+            Err(Day24Error::NoModelNumber)
+        ));
+
+        let error = Day24::parse("inp w\n".repeat(20).as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "model numbers of 20 digits don't fit in 19"
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let monad =
+            Monad::parse("inp w\nadd z w\ninp w\nadd z 2\neql z w\neql z 0\n".as_bytes()).unwrap();
+
+        let validation = monad.validate("35").unwrap();
+        assert!(validation.is_valid());
+        assert_eq!(validation.steps.len(), 6);
+        assert_eq!(validation.steps[1].after, [0, 0, 3, 3]);
+        assert_eq!(validation.steps[1].z_change(), Ordering::Greater);
+        assert_eq!(validation.steps[5].z_change(), Ordering::Less);
+        assert_eq!(
+            validation.steps[3].to_string(),
+            "   4  add z 2      [0, 0, 5, 5]"
+        );
+
+        assert!(!monad.validate("36").unwrap().is_valid());
+
+        let error = monad.validate("3").unwrap_err();
+        assert_eq!(error.to_string(), "instruction 3: ran out of input");
+        let error = monad.validate("357").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "model number has 3 digits, but the program reads 2"
+        );
+        let error = monad.validate("30").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid model number '30', expected digits 1 to 9"
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Monad::parse("inp w\nadd x q\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: invalid operand 'q', expected a register or a number"
+        );
+
+        let error = Monad::parse("inp w\nsub x 1\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: invalid instruction 'sub'"
+        );
+
+        let error = Monad::parse("inp v\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: invalid register 'v', expected w, x, y or z"
+        );
+
+        let error = Monad::parse("inp w\nmul x\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 6: missing operand");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(24);
+        let model_numbers = Day24::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day24::part1(&model_numbers).unwrap(), 94992994195998);
+        assert_eq!(Day24::part2(&model_numbers).unwrap(), 21191861151161);
+    }
+
+    #[test]
+    #[ignore = "exhaustive search over the real input takes a few minutes"]
+    fn real_input_search() {
+        let input = common::input::require_workspace_input(24);
+        let monad = Monad::parse(input.as_slice()).unwrap();
+
+        assert_eq!(
+            search(monad.program()),
+            Some((21191861151161, 94992994195998))
+        );
+    }
+}
@@ -1,47 +1,114 @@
-use rayon::prelude::*;
-
-include!(concat!(env!("OUT_DIR"), "/gen.rs"));
-
-fn main() {
-    let mut state = vec![([0; 4], (0, 0))];
-
-    for (f, r) in PASSES.iter().zip(INPUTS) {
-        // Clear the register that's about to be written
-        state.par_iter_mut().for_each(|k| k.0[r] = 0);
-
-        // Sort by register state, then do single-pass compaction
-        state.par_sort_unstable_by_key(|k| k.0);
-        let mut i = 0;
-        let mut j = 1;
-        while j < state.len() {
-            if state[i].0 == state[j].0 {
-                let (imin, imax) = state[i].1;
-                let (jmin, jmax) = state[j].1;
-                state[i].1 = (imin.min(jmin), imax.max(jmax));
-            } else {
-                i += 1;
-                state[i] = state[j];
+use std::cmp::Ordering;
+use std::error::Error;
+use std::io::{self, IsTerminal};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use common::cli::DayArgs;
+use common::input::InputArgs;
+use day24::{Day24, Day24Error, Monad, Register};
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    day: DayArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Explain the digit constraints the program checks
+    Explain {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Print the program as pseudocode, after simplifying it
+    Disassemble {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Run the program on a model number, printing the x, y, z and w registers after every instruction
+    Validate {
+        /// Model number to check, e.g. 13579246899999
+        number: String,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+fn explain(input: &InputArgs) -> Result<(), Box<dyn Error>> {
+    let monad = Monad::parse(input.open(24)?)?;
+    let Some(analysis) = monad.analysis() else {
+        println!("The program doesn't follow the MONAD block structure, only the exhaustive search can solve it");
+        return Ok(());
+    };
+
+    println!("{}", analysis);
+    match analysis.model_numbers() {
+        Ok((min, max)) => println!("Largest model number {}, smallest {}", max, min),
+        Err(Day24Error::NoModelNumber) => println!("No model number satisfies every constraint"),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(())
+}
+
+fn disassemble(input: &InputArgs) -> Result<(), Box<dyn Error>> {
+    let monad = Monad::parse(input.open(24)?)?;
+    let optimized = monad.optimized();
+
+    print!("{}", optimized);
+    println!(
+        "\n{} instructions simplified to {}",
+        monad.program().len(),
+        optimized.statements.len()
+    );
+
+    Ok(())
+}
+
+fn validate(input: &InputArgs, number: &str) -> Result<(), Box<dyn Error>> {
+    let monad = Monad::parse(input.open(24)?)?;
+    let validation = monad.validate(number)?;
+
+    // Steps changing z are colored when printing to a terminal, and marked either way
+    let color = io::stdout().is_terminal();
+    for step in &validation.steps {
+        let (marker, color_code) = match step.z_change() {
+            Ordering::Greater => ("  z grew", 31),
+            Ordering::Less => ("  z shrank", 32),
+            Ordering::Equal => {
+                println!("{}", step);
+                continue;
             }
-            j += 1;
+        };
+        if color {
+            println!("\x1b[{}m{}{}\x1b[0m", color_code, step, marker);
+        } else {
+            println!("{}{}", step, marker);
         }
-        assert!(i < state.len());
-        state.resize(i + 1, ([0; 4], (0, 0)));
-
-        state = (1..=9)
-            .into_par_iter()
-            .flat_map(|i| {
-                state.par_iter().map(move |(regs, (min, max))| {
-                    (f(*regs, i), (min * 10 + i as usize, max * 10 + i as usize))
-                })
-            })
-            .collect();
     }
 
-    let (min, max) = state
-        .par_iter()
-        .filter(|(k, _)| k[2] == 0)
-        .map(|(_, v)| *v)
-        .reduce(|| (usize::MAX, 0), |a, b| (a.0.min(b.0), a.1.max(b.1)));
-    println!("Part 1: {}", max);
-    println!("Part 2: {}", min);
-}
\ No newline at end of file
+    let z = validation.registers[Register::Z.index()];
+    if validation.is_valid() {
+        println!("{} is a valid model number", number);
+    } else {
+        println!("{} is not a valid model number, z ends up at {}", number, z);
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    common::cli::exit(match &args.command {
+        Some(Command::Explain { input }) => explain(input),
+        Some(Command::Disassemble { input }) => disassemble(input),
+        Some(Command::Validate { number, input }) => validate(input, number),
+        None => common::cli::run::<Day24>(24, &args.day),
+    })
+}
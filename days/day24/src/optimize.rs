@@ -0,0 +1,572 @@
+use std::fmt::{self, Display};
+
+use crate::{add, divide, modulo, multiply, read, Alu, Day24Error, Instruction, Operand, Register};
+
+// Base of the numbers MONAD keeps on its z stack
+const STACK_BASE: i64 = 26;
+
+// Registers that are read later on, indexed like `Registers`
+pub type Live = [bool; 4];
+
+// Smallest and largest values a register can hold
+type Bounds = (i64, i64);
+
+const UNBOUNDED: Bounds = (i64::MIN, i64::MAX);
+
+// Programs only ever read the digits of model numbers
+const DIGIT: Bounds = (1, 9);
+
+// Bounds of `f` over the corners of `a` and `b`, which hold its extremes for the ops it's used on.
+// `None` when one of them overflows
+fn corners(a: Bounds, b: Bounds, f: impl Fn(i128, i128) -> i128) -> Option<Bounds> {
+    let values =
+        [(a.0, b.0), (a.0, b.1), (a.1, b.0), (a.1, b.1)].map(|(x, y)| f(x as i128, y as i128));
+    let min = *values.iter().min().unwrap();
+    let max = *values.iter().max().unwrap();
+
+    Some((i64::try_from(min).ok()?, i64::try_from(max).ok()?))
+}
+
+// Instructions of the simplified program, which can also set a register directly or work on a stack of
+// base 26 digits held in a register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Inp(Register),
+    Set(Register, Operand),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+    Neq(Register, Operand),
+    // a = b % 26, the digit on top of the stack in b
+    Peek(Register, Register),
+    // a = a / 26, dropping the digit on top of the stack
+    Pop(Register),
+}
+
+impl Op {
+    fn written(self) -> Register {
+        match self {
+            Self::Inp(a)
+            | Self::Set(a, _)
+            | Self::Add(a, _)
+            | Self::Mul(a, _)
+            | Self::Div(a, _)
+            | Self::Mod(a, _)
+            | Self::Eql(a, _)
+            | Self::Neq(a, _)
+            | Self::Peek(a, _)
+            | Self::Pop(a) => a,
+        }
+    }
+
+    // Registers read by the op, the written one included unless it's overwritten
+    fn read(self) -> [Option<Register>; 2] {
+        let operand = |b| match b {
+            Operand::Register(register) => Some(register),
+            Operand::Literal(_) => None,
+        };
+
+        match self {
+            Self::Inp(_) => [None, None],
+            Self::Set(_, b) => [operand(b), None],
+            Self::Peek(_, b) => [Some(b), None],
+            Self::Pop(a) => [Some(a), None],
+            Self::Add(a, b)
+            | Self::Mul(a, b)
+            | Self::Div(a, b)
+            | Self::Mod(a, b)
+            | Self::Eql(a, b)
+            | Self::Neq(a, b) => [Some(a), operand(b)],
+        }
+    }
+
+    // Bounds of the value the op writes, `None` when it might overflow
+    fn bounds(self, before: &[Bounds; 4]) -> Option<Bounds> {
+        let operand = |b| match b {
+            Operand::Register(register) => before[register.index()],
+            Operand::Literal(value) => (value, value),
+        };
+        let register = |a: Register| before[a.index()];
+
+        match self {
+            Self::Inp(_) => Some(DIGIT),
+            Self::Set(_, b) => Some(operand(b)),
+            Self::Add(a, b) => corners(register(a), operand(b), |x, y| x + y),
+            Self::Mul(a, b) => corners(register(a), operand(b), |x, y| x * y),
+            Self::Div(a, b) => match operand(b) {
+                (min, max) if min <= 0 && max >= 0 => Some(UNBOUNDED),
+                b => corners(register(a), b, |x, y| x / y),
+            },
+            Self::Mod(_, b) => Some((0, operand(b).1.saturating_sub(1).max(0))),
+            Self::Eql(..) | Self::Neq(..) => Some((0, 1)),
+            Self::Peek(..) => Some((0, STACK_BASE - 1)),
+            Self::Pop(a) => corners(register(a), (STACK_BASE, STACK_BASE), |x, y| x / y),
+        }
+    }
+
+    // Ops that can fail or read input have to stay even when their result is never used,
+    // overflows included
+    fn has_effect(self, before: &[Bounds; 4]) -> bool {
+        match self {
+            Self::Inp(_) | Self::Mod(..) | Self::Peek(..) => true,
+            Self::Div(_, b) if !matches!(b, Operand::Literal(value) if value != 0) => true,
+            _ => self.bounds(before).is_none(),
+        }
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inp(a) => write!(f, "{} = next digit", a),
+            Self::Set(a, b) => write!(f, "{} = {}", a, b),
+            Self::Add(a, Operand::Literal(value)) if *value < 0 => {
+                write!(f, "{} -= {}", a, -value)
+            }
+            Self::Add(a, b) => write!(f, "{} += {}", a, b),
+            Self::Mul(a, b) => write!(f, "{} *= {}", a, b),
+            Self::Div(a, b) => write!(f, "{} /= {}", a, b),
+            Self::Mod(a, b) => write!(f, "{} %= {}", a, b),
+            Self::Eql(a, b) => write!(f, "{} = {} == {}", a, a, b),
+            Self::Neq(a, b) => write!(f, "{} = {} != {}", a, a, b),
+            Self::Peek(a, b) => write!(f, "{} = top of {}", a, b),
+            Self::Pop(a) => write!(f, "pop {}", a),
+        }
+    }
+}
+
+// An op along with the index of the instruction it comes from, which errors point at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Statement {
+    pub index: usize,
+    pub op: Op,
+}
+
+impl Alu {
+    pub fn execute_statement(
+        &mut self,
+        statement: Statement,
+        input: &mut impl Iterator<Item = i64>,
+    ) -> Result<(), Day24Error> {
+        let index = statement.index;
+        let (a, value) = match statement.op {
+            Op::Inp(a) => (a, read(index, input)?),
+            Op::Set(a, b) => (a, self.value(b)),
+            Op::Add(a, b) => (a, add(index, self.registers[a.index()], self.value(b))?),
+            Op::Mul(a, b) => (
+                a,
+                multiply(index, self.registers[a.index()], self.value(b))?,
+            ),
+            Op::Div(a, b) => (a, divide(index, self.registers[a.index()], self.value(b))?),
+            Op::Mod(a, b) => (a, modulo(index, self.registers[a.index()], self.value(b))?),
+            Op::Eql(a, b) => (a, (self.registers[a.index()] == self.value(b)) as i64),
+            Op::Neq(a, b) => (a, (self.registers[a.index()] != self.value(b)) as i64),
+            Op::Peek(a, b) => (a, modulo(index, self.registers[b.index()], STACK_BASE)?),
+            Op::Pop(a) => (a, self.registers[a.index()] / STACK_BASE),
+        };
+
+        self.registers[a.index()] = value;
+
+        Ok(())
+    }
+}
+
+// A program simplified for running and reading, only keeping z intact since it's all MONAD checks
+#[derive(Debug, Clone)]
+pub struct Optimized {
+    pub statements: Vec<Statement>,
+    // Registers live before every statement
+    pub live: Vec<Live>,
+}
+
+impl Optimized {
+    pub fn new(program: &[Instruction]) -> Self {
+        let statements = fold_constants(program);
+        let statements = combine(statements);
+        let statements = remove_dead_stores(statements);
+        let live = liveness(&statements);
+
+        Optimized { statements, live }
+    }
+
+    // Registers live right after the statement at `i`
+    pub fn live_after(&self, i: usize) -> Live {
+        self.live.get(i + 1).copied().unwrap_or(LIVE_OUT)
+    }
+}
+
+// Pseudocode with one line per op, numbered after the instruction it comes from
+impl Display for Optimized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digit = 0;
+        for statement in &self.statements {
+            if let Op::Inp(_) = statement.op {
+                digit += 1;
+                writeln!(f, "\ndigit {}:", digit)?;
+            }
+            writeln!(f, "{:>4}  {}", statement.index + 1, statement.op)?;
+        }
+
+        Ok(())
+    }
+}
+
+const LIVE_OUT: Live = [false, false, true, false];
+
+// Keeps track of the registers known to hold a constant, only emitting the ops that depend on input
+fn fold_constants(program: &[Instruction]) -> Vec<Statement> {
+    let mut known = [Some(0); 4];
+    let mut statements = Vec::new();
+
+    for (index, &instruction) in program.iter().enumerate() {
+        let mut emit = |op| statements.push(Statement { index, op });
+        let (a, b, op): (_, _, fn(Register, Operand) -> Op) = match instruction {
+            Instruction::Inp(a) => {
+                emit(Op::Inp(a));
+                known[a.index()] = None;
+                continue;
+            }
+            Instruction::Add(a, b) => (a, b, Op::Add),
+            Instruction::Mul(a, b) => (a, b, Op::Mul),
+            Instruction::Div(a, b) => (a, b, Op::Div),
+            Instruction::Mod(a, b) => (a, b, Op::Mod),
+            Instruction::Eql(a, b) => (a, b, Op::Eql),
+        };
+
+        let b_value = match b {
+            Operand::Register(register) => known[register.index()],
+            Operand::Literal(value) => Some(value),
+        };
+        let b = b_value.map_or(b, Operand::Literal);
+
+        known[a.index()] = match (known[a.index()], b_value, instruction) {
+            (Some(a_value), Some(_), _) => {
+                let mut alu = Alu::default();
+                alu.registers[a.index()] = a_value;
+                let folded = alu.execute(
+                    index,
+                    op_instruction(instruction, b),
+                    &mut std::iter::empty(),
+                );
+                if folded.is_ok() {
+                    Some(alu.registers[a.index()])
+                } else {
+                    // Left for the ALU to fail on
+                    emit(Op::Set(a, Operand::Literal(a_value)));
+                    emit(op(a, b));
+                    None
+                }
+            }
+            (Some(0), None, Instruction::Mul(..)) => Some(0),
+            (
+                Some(a_value),
+                None,
+                Instruction::Add(..) | Instruction::Mul(..) | Instruction::Eql(..),
+            ) => {
+                // Commutative, so b can be copied over and combined with the constant
+                emit(Op::Set(a, b));
+                match (a_value, instruction) {
+                    (0, Instruction::Add(..)) | (1, Instruction::Mul(..)) => {}
+                    _ => emit(op(a, Operand::Literal(a_value))),
+                }
+                None
+            }
+            (Some(a_value), None, _) => {
+                emit(Op::Set(a, Operand::Literal(a_value)));
+                emit(op(a, b));
+                None
+            }
+            (None, Some(0), Instruction::Mul(..)) => Some(0),
+            (None, Some(0), Instruction::Add(..))
+            | (None, Some(1), Instruction::Mul(..) | Instruction::Div(..)) => None,
+            (None, _, _) => {
+                emit(op(a, b));
+                None
+            }
+        };
+    }
+
+    // Constants that have been folded away still have to end up in their registers
+    for (i, value) in known.iter().enumerate() {
+        if let (true, Some(value)) = (LIVE_OUT[i], value) {
+            statements.push(Statement {
+                index: program.len().saturating_sub(1),
+                op: Op::Set(Register::ALL[i], Operand::Literal(*value)),
+            });
+        }
+    }
+
+    statements
+}
+
+fn op_instruction(instruction: Instruction, b: Operand) -> Instruction {
+    match instruction {
+        Instruction::Inp(a) => Instruction::Inp(a),
+        Instruction::Add(a, _) => Instruction::Add(a, b),
+        Instruction::Mul(a, _) => Instruction::Mul(a, b),
+        Instruction::Div(a, _) => Instruction::Div(a, b),
+        Instruction::Mod(a, _) => Instruction::Mod(a, b),
+        Instruction::Eql(a, _) => Instruction::Eql(a, b),
+    }
+}
+
+// Peephole pass turning `eql` then `eql 0` into `neq`, and mod and div by 26 into stack operations
+fn combine(statements: Vec<Statement>) -> Vec<Statement> {
+    let mut combined: Vec<Statement> = Vec::with_capacity(statements.len());
+    let base = Operand::Literal(STACK_BASE);
+
+    for statement in statements {
+        let previous = combined.last_mut().map(|previous| &mut previous.op);
+        match (previous, statement.op) {
+            (Some(previous @ Op::Eql(..)), Op::Eql(a, Operand::Literal(0)))
+                if previous.written() == a =>
+            {
+                let Op::Eql(a, b) = *previous else {
+                    unreachable!()
+                };
+                *previous = Op::Neq(a, b);
+            }
+            (Some(Op::Set(a, Operand::Register(b))), Op::Mod(c, modulus))
+                if *a == c && modulus == base =>
+            {
+                let (a, b) = (*a, *b);
+                combined.pop();
+                combined.push(Statement {
+                    index: statement.index,
+                    op: Op::Peek(a, b),
+                });
+            }
+            (_, Op::Div(a, divisor)) if divisor == base => combined.push(Statement {
+                index: statement.index,
+                op: Op::Pop(a),
+            }),
+            _ => combined.push(statement),
+        }
+    }
+
+    combined
+}
+
+fn update_live(live: &mut Live, op: Op) {
+    live[op.written().index()] = false;
+    for register in op.read().into_iter().flatten() {
+        live[register.index()] = true;
+    }
+}
+
+// Bounds of every register before every statement, registers starting out at 0
+fn register_bounds(statements: &[Statement]) -> Vec<[Bounds; 4]> {
+    let mut bounds = [(0, 0); 4];
+    statements
+        .iter()
+        .map(|statement| {
+            let before = bounds;
+            bounds[statement.op.written().index()] =
+                statement.op.bounds(&before).unwrap_or(UNBOUNDED);
+            before
+        })
+        .collect()
+}
+
+// Drops the ops whose result is overwritten or never read, walking the program backwards
+fn remove_dead_stores(statements: Vec<Statement>) -> Vec<Statement> {
+    let bounds = register_bounds(&statements);
+    let mut live = LIVE_OUT;
+    let mut kept: Vec<Statement> = statements
+        .into_iter()
+        .zip(bounds)
+        .rev()
+        .filter(|(statement, before)| {
+            let needed = live[statement.op.written().index()] || statement.op.has_effect(before);
+            if needed {
+                update_live(&mut live, statement.op);
+            }
+            needed
+        })
+        .map(|(statement, _)| statement)
+        .collect();
+    kept.reverse();
+
+    kept
+}
+
+fn liveness(statements: &[Statement]) -> Vec<Live> {
+    let mut live = LIVE_OUT;
+    let mut before: Vec<Live> = statements
+        .iter()
+        .rev()
+        .map(|statement| {
+            update_live(&mut live, statement.op);
+            live
+        })
+        .collect();
+    before.reverse();
+
+    before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::block_template;
+    use crate::Monad;
+
+    fn run_z(statements: &[Statement], digits: &[i64]) -> Option<i64> {
+        let mut alu = Alu::default();
+        let mut input = digits.iter().copied();
+        for &statement in statements {
+            alu.execute_statement(statement, &mut input).ok()?;
+        }
+
+        Some(alu.registers[Register::Z.index()])
+    }
+
+    #[test]
+    fn simplify_block() {
+        let monad = Monad::parse(
+            "\
+inp w
+mul x 0
+add x z
+mod x 26
+div z 1
+add x 12
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+"
+            .as_bytes(),
+        )
+        .unwrap();
+        let optimized = Optimized::new(monad.program());
+
+        // z starts out at 0 and only gets multiplied, so nothing but reading the digit is left
+        assert_eq!(
+            optimized.to_string(),
+            "\ndigit 1:\n   1  w = next digit\n  13  z = 0\n"
+        );
+    }
+
+    #[test]
+    fn neq_and_stack_ops() {
+        let monad = Monad::parse(
+            "\
+inp w
+inp z
+mul x 0
+add x z
+mod x 26
+add x -3
+eql x w
+eql x 0
+div z 26
+mul z x
+"
+            .as_bytes(),
+        )
+        .unwrap();
+        let ops: Vec<Op> = Optimized::new(monad.program())
+            .statements
+            .iter()
+            .map(|statement| statement.op)
+            .collect();
+
+        use Register::*;
+        assert_eq!(
+            ops,
+            [
+                Op::Inp(W),
+                Op::Inp(Z),
+                Op::Peek(X, Z),
+                Op::Add(X, Operand::Literal(-3)),
+                Op::Neq(X, Operand::Register(W)),
+                Op::Pop(Z),
+                Op::Mul(Z, Operand::Register(X)),
+            ]
+        );
+    }
+
+    // The optimized program must leave the same z behind, and fail on the same model numbers
+    #[test]
+    fn same_results() {
+        let program: Vec<Instruction> = [(1, 12, 6), (1, 11, 4), (26, -7, 3), (26, -2, 15)]
+            .into_iter()
+            .flat_map(|(div_z, add_x, add_y)| block_template(div_z, add_x, add_y))
+            .collect();
+        let optimized = Optimized::new(&program);
+        assert!(optimized.statements.len() < program.len() * 3 / 4);
+
+        for number in 1111..=9999 {
+            let digits: Vec<i64> = number
+                .to_string()
+                .bytes()
+                .map(|b| (b - b'0') as i64)
+                .collect();
+            if digits.contains(&0) {
+                continue;
+            }
+
+            let mut alu = Alu::default();
+            let expected = alu
+                .run(0, &program, &mut digits.iter().copied())
+                .ok()
+                .map(|_| alu.registers[Register::Z.index()]);
+            assert_eq!(
+                run_z(&optimized.statements, &digits),
+                expected,
+                "{}",
+                number
+            );
+        }
+
+        let monad = Monad::parse(
+            "inp z\nmod z 2\nadd y 3\nmul y z\nadd z y\nadd x 5\ndiv x 0\n".as_bytes(),
+        )
+        .unwrap();
+        let optimized = Optimized::new(monad.program());
+        assert_eq!(run_z(&optimized.statements, &[5]), None);
+        let error = Alu::default()
+            .execute_statement(
+                *optimized.statements.last().unwrap(),
+                &mut std::iter::empty(),
+            )
+            .unwrap_err();
+        assert_eq!(error.to_string(), "instruction 7: division by zero");
+
+        // Dead ops that overflow still make the run fail, so no model number is accepted
+        let monad = Monad::parse(
+            "inp w\nadd z w\nadd z -1\ninp x\nmul x 999999999999\nmul x x\n".as_bytes(),
+        )
+        .unwrap();
+        let optimized = Optimized::new(monad.program());
+        for digits in (1..=9).flat_map(|a| (1..=9).map(move |b| [a, b])) {
+            assert!(Alu::default()
+                .run(0, monad.program(), &mut digits.into_iter())
+                .is_err());
+            assert_eq!(run_z(&optimized.statements, &digits), None);
+        }
+        assert_eq!(crate::search(monad.program()), None);
+
+        // Overflows fail on the same instruction as with the ALU
+        let monad =
+            Monad::parse("inp z\nmul z 999999999999\nadd y 2\nmul z z\n".as_bytes()).unwrap();
+        let optimized = Optimized::new(monad.program());
+        let (mut alu, mut input) = (Alu::default(), [9].into_iter());
+        let error = optimized
+            .statements
+            .iter()
+            .try_for_each(|&statement| alu.execute_statement(statement, &mut input))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "instruction 4: overflow");
+        let error = Alu::default()
+            .run(0, monad.program(), &mut [9].into_iter())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "instruction 4: overflow");
+    }
+}
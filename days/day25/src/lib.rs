@@ -0,0 +1,166 @@
+use std::fmt;
+use std::io;
+use std::io::BufRead;
+
+use common::grid::{Grid, ParseGridError};
+use common::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day25Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Grid(#[from] ParseGridError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    East,
+    South,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SeaCucumber {
+    move_direction: Direction,
+}
+
+impl SeaCucumber {
+    fn new(move_direction: Direction) -> Self {
+        SeaCucumber { move_direction }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Trench {
+    spaces: Grid<Option<SeaCucumber>>,
+}
+
+impl Trench {
+    fn new(spaces: Grid<Option<SeaCucumber>>) -> Self {
+        Trench { spaces }
+    }
+
+    // Moves every cucumber facing `direction` that has a free space in front of it
+    fn move_herd(&mut self, direction: Direction, delta: (isize, isize)) -> bool {
+        let mut moves = Vec::new();
+        for (pos, space) in self.spaces.indexed_iter() {
+            if space.is_some_and(|cuc| cuc.move_direction == direction) {
+                let dest = self.spaces.offset_wrapping(pos, delta);
+                if self.spaces[dest].is_none() {
+                    moves.push((pos, dest));
+                }
+            }
+        }
+
+        for &(curr, dest) in &moves {
+            self.spaces[dest] = self.spaces[curr].take();
+        }
+
+        !moves.is_empty()
+    }
+
+    fn run_step(&mut self) -> bool {
+        // First try to move east facing cucumbers, then south facing ones
+        let moved_east = self.move_herd(Direction::East, (0, 1));
+        let moved_south = self.move_herd(Direction::South, (1, 0));
+
+        moved_east || moved_south
+    }
+
+    fn run_until_end(&mut self) -> usize {
+        let mut step_count = 1;
+        while self.run_step() {
+            step_count += 1
+        }
+
+        step_count
+    }
+}
+
+impl fmt::Display for Trench {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let trench_str = self.spaces.render(|space| match space {
+            Some(cuc) if cuc.move_direction == Direction::East => '>',
+            Some(_) => 'v',
+            None => '.',
+        });
+
+        write!(f, "{}", trench_str)
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Trench;
+    type Output1 = usize;
+    type Output2 = &'static str;
+    type Error = Day25Error;
+
+    fn parse<R: BufRead>(input: R) -> Result<Self::Input, Day25Error> {
+        let spaces = Grid::parse(input, |c| match c {
+            '.' => Some(None),
+            '>' => Some(Some(SeaCucumber::new(Direction::East))),
+            'v' => Some(Some(SeaCucumber::new(Direction::South))),
+            _ => None,
+        })?;
+
+        Ok(Trench::new(spaces))
+    }
+
+    fn part1(trench: &Self::Input) -> Result<usize, Day25Error> {
+        let mut trench = trench.clone();
+
+        Ok(trench.run_until_end())
+    }
+
+    // There's no puzzle for the second part of the last day
+    fn part2(_trench: &Self::Input) -> Result<&'static str, Day25Error> {
+        Ok("Merry Christmas!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day25::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Day25::part1(&input).unwrap(), 58);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day25::parse("v...>>.vv>\n.vv>>.v<v>\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 8: invalid cell '<'");
+
+        let error = Day25::parse("v...>>.vv>\n.vv>>.vv\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 10 cells, found 8"
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = common::input::require_workspace_input(25);
+        let input = Day25::parse(input.as_slice()).unwrap();
+
+        assert_eq!(Day25::part1(&input).unwrap(), 601);
+    }
+}